@@ -0,0 +1,88 @@
+//! Embeds the puzzle inputs into the solution binaries if the `embed-inputs` feature is enabled.
+//!
+//! For every binary in `src/bin`, an expression is written to `$OUT_DIR/inputs/<bin>.rs` that
+//! either includes the bytes of its input, or fails compilation with an explanation if the input
+//! is missing. `solution!` includes this expression.
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    if env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_none() {
+        return;
+    }
+
+    let data_dir = data_dir();
+    println!("cargo:rerun-if-changed=aoc.toml");
+    println!("cargo:rerun-if-changed=src/bin");
+    println!("cargo:rerun-if-changed={}", data_dir.display());
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap()).join("inputs");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    for entry in fs::read_dir("src/bin").unwrap().flatten() {
+        let path = entry.path();
+        if path.extension().is_none_or(|ext| ext != "rs") {
+            continue;
+        }
+
+        let bin_name = path.file_stem().unwrap().to_string_lossy().to_string();
+        let input = input_path(&data_dir, &bin_name);
+        fs::write(
+            out_dir.join(format!("{bin_name}.rs")),
+            embed(&bin_name, &input),
+        )
+        .unwrap();
+    }
+}
+
+/// Returns the data directory configured in `aoc.toml`.
+fn data_dir() -> PathBuf {
+    let config = fs::read_to_string("aoc.toml").unwrap_or_default();
+    let config: toml::Table = config.parse().expect("aoc.toml is not valid TOML");
+
+    config
+        .get("paths")
+        .and_then(|paths| paths.get("data"))
+        .and_then(|data| data.as_str())
+        .map_or_else(|| PathBuf::from("data"), PathBuf::from)
+}
+
+/// Returns the input of a binary, e.g. `data/inputs/01.txt` for `01` or
+/// `data/2025/inputs/01.txt` for `2025-01`.
+fn input_path(data_dir: &Path, bin_name: &str) -> PathBuf {
+    match bin_name.split_once('-') {
+        Some((year, day)) => data_dir
+            .join(year)
+            .join("inputs")
+            .join(format!("{day}.txt")),
+        None => data_dir.join("inputs").join(format!("{bin_name}.txt")),
+    }
+}
+
+fn embed(bin_name: &str, input: &Path) -> String {
+    let day = bin_name
+        .rsplit('-')
+        .next()
+        .unwrap_or(bin_name)
+        .trim_start_matches('0');
+    let error = if input.is_file() {
+        let path = fs::canonicalize(input).unwrap();
+        return format!("include_bytes!({:?})", path.to_string_lossy());
+    } else if input.with_extension("txt.enc").is_file() {
+        format!(
+            "The input of `{bin_name}` at \"{}\" is encrypted and can not be embedded. Decrypt it with `cargo decrypt`, or build without the `embed-inputs` feature.",
+            input.display()
+        )
+    } else {
+        format!(
+            "There is no input to embed for `{bin_name}` at \"{}\". Download it with `cargo download {day}`, or build without the `embed-inputs` feature.",
+            input.display()
+        )
+    };
+
+    format!("compile_error!({error:?})")
+}
@@ -1,3 +1,5 @@
+use advent_of_code::template::parse::{ParseError, Source};
+
 advent_of_code::solution!(1);
 
 struct Move {
@@ -5,18 +7,26 @@ struct Move {
     distance: i32,
 }
 
-fn parse_line(input: &str) -> Move {
-    let c = input.chars().next().unwrap();
-    let number = input[1..].parse().unwrap();
+fn parse_line(source: &Source, line: &str) -> Result<Move, ParseError> {
+    let c = match line.chars().next() {
+        Some(c @ ('L' | 'R')) => c,
+        _ => return Err(source.error(line, "expected a rotation starting with `L` or `R`")),
+    };
+    let number = source.parse(&line[1..])?;
 
-    Move{
+    Ok(Move{
         direction: c,
         distance: number
-    }
+    })
+}
+
+fn parse(input: &str) -> Result<Vec<Move>, ParseError> {
+    let source = Source::new(input);
+    input.lines().map(|line| parse_line(&source, line)).collect()
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let input: Vec<Move> = input.lines().map(parse_line).collect();
+pub fn part_one(input: &str) -> Result<u64, ParseError> {
+    let input = parse(input)?;
 
     let mut dial = 50;
     let mut count = 0;
@@ -35,11 +45,11 @@ pub fn part_one(input: &str) -> Option<u64> {
         }
     }
 
-    Some(count)
+    Ok(count)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let input: Vec<Move> = input.lines().map(parse_line).collect();
+pub fn part_two(input: &str) -> Result<u64, ParseError> {
+    let input = parse(input)?;
 
     let mut dial = 50i32;
     let mut count = 0;
@@ -63,7 +73,7 @@ pub fn part_two(input: &str) -> Option<u64> {
         }
     }
 
-    Some(count)
+    Ok(count)
 }
 
 #[cfg(test)]
@@ -73,12 +83,12 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3));
+        assert_eq!(result, Ok(3));
     }
 
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(6));
+        assert_eq!(result, Ok(6));
     }
 }
@@ -1,12 +1,14 @@
 #![feature(new_range_api)]
 use std::{collections::HashSet, range::RangeInclusive};
 
+use advent_of_code::template::parse::{ParseError, Source};
+
 advent_of_code::solution!(2);
 
 type Input = Vec<RangeInclusive<u64>>;
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let input = parse(input);
+pub fn part_one(input: &str) -> Result<u64, ParseError> {
+    let input = parse(input)?;
 
 
     let sum = input.iter().map(|range|
@@ -22,11 +24,11 @@ pub fn part_one(input: &str) -> Option<u64> {
     )
     .sum();
 
-    return Some(sum);
+    return Ok(sum);
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let input = parse(input);
+pub fn part_two(input: &str) -> Result<u64, ParseError> {
+    let input = parse(input)?;
 
 
     let sum = input.iter().map(|range|
@@ -42,7 +44,7 @@ pub fn part_two(input: &str) -> Option<u64> {
     )
     .sum();
 
-    return Some(sum);
+    return Ok(sum);
 }
 
 fn check_double(n: u64) -> bool {
@@ -54,18 +56,18 @@ fn check_double(n: u64) -> bool {
     return left == right;
 }
 
-fn parse_line(line: &str) -> RangeInclusive<u64> {
-    let numbers: Vec<u64> = line.split('-').map(|n| n.parse().unwrap()).collect();
-    assert!(numbers.len() == 2);
-    RangeInclusive::from(numbers[0]..=numbers[1])
+fn parse_line(source: &Source, line: &str) -> Result<RangeInclusive<u64>, ParseError> {
+    let (start, end) = source.split_once(line, "-")?;
+    Ok(RangeInclusive::from(source.parse(start)?..=source.parse(end)?))
 }
 
-fn parse(input: &str) -> Input {
+fn parse(input: &str) -> Result<Input, ParseError> {
+    let source = Source::new(input);
     input.split('\n')
         .next()
-        .unwrap()
+        .unwrap_or_default()
         .split(',')
-        .map(parse_line)
+        .map(|line| parse_line(&source, line))
         .collect()
 }
 
@@ -115,13 +117,13 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(1227775554));
+        assert_eq!(result, Ok(1227775554));
     }
 
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4174379265));
+        assert_eq!(result, Ok(4174379265));
     }
 
     #[test]
@@ -1,32 +1,38 @@
 
+use advent_of_code::template::parse::{ParseError, Source};
+
 advent_of_code::solution!(3);
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let input = parse(input);
+pub fn part_one(input: &str) -> Result<u64, ParseError> {
+    let input = parse(input)?;
     let res = input.iter()
         .map(|list| find_n_biggest(list, 2))
         .map(|list| list.into_iter().fold(0, |acc, item| item as u64 + acc*10))
         .sum();
-    Some(res)
+    Ok(res)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let input = parse(input);
+pub fn part_two(input: &str) -> Result<u64, ParseError> {
+    let input = parse(input)?;
     let res = input.iter()
         .map(|list| find_n_biggest(list, 12))
         .map(|list| list.into_iter().fold(0, |acc, item| item as u64 + acc*10))
         .sum();
-    Some(res)
+    Ok(res)
 }
 
 type Bank = Vec<u32>;
 type Input = Vec<Bank>;
 
-fn parse(input: &str) -> Input {
+fn parse(input: &str) -> Result<Input, ParseError> {
+    let source = Source::new(input);
     input.split('\n')
         .into_iter()
         .filter(|line| !line.is_empty())
-        .map(|line| line.chars().map(|c| c.to_string().parse().unwrap()).collect())
+        .map(|line| line.char_indices()
+            .map(|(i, c)| c.to_digit(10)
+                .ok_or_else(|| source.error(&line[i..i + c.len_utf8()], "expected a digit")))
+            .collect())
         .collect()
 }
 
@@ -61,18 +67,18 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(357));
+        assert_eq!(result, Ok(357));
     }
 
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3121910778619));
+        assert_eq!(result, Ok(3121910778619));
     }
 
     #[test]
     fn test_parse() {
-        assert_eq!(vec!(vec!(1, 2, 3), vec!(4, 5, 6)), parse(&"123\n456\n"));
+        assert_eq!(vec!(vec!(1, 2, 3), vec!(4, 5, 6)), parse(&"123\n456\n").unwrap());
     }
 
     #[test]
@@ -3,6 +3,8 @@
 use core::slice::GetDisjointMutIndex;
 use std::{collections::HashSet, ops::RangeInclusive};
 
+use advent_of_code::template::parse::{ParseError, Source};
+
 advent_of_code::solution!(5);
 
 struct Input {
@@ -10,18 +12,18 @@ struct Input {
     ingredients: Vec<usize>,
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let input = parse(input);
+pub fn part_one(input: &str) -> Result<usize, ParseError> {
+    let input = parse(input)?;
 
     let ingredients: Vec<_> = input.ingredients.iter()
         .filter(|i| in_range(**i, &input.range_list))
         .collect();
 
-    Some(ingredients.len())
+    Ok(ingredients.len())
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let input = parse(input);
+pub fn part_two(input: &str) -> Result<usize, ParseError> {
+    let input = parse(input)?;
 
     let mut ranges = input.range_list;
     ranges.sort_by_key(|obj| *obj.start());
@@ -40,7 +42,7 @@ pub fn part_two(input: &str) -> Option<usize> {
 
     let res = simplified.iter().map(|range| range.end() - range.start() + 1).sum();
 
-    Some(res)
+    Ok(res)
 }
 
 fn in_range(ingredient: usize, range_list: &Vec<RangeInclusive<usize>>) -> bool {
@@ -55,24 +57,23 @@ fn simplify(first: &RangeInclusive<usize>, second: &RangeInclusive<usize>) -> Ve
     }
 }
 
-fn parse(input: &str) -> Input {
-    let mut splitted = input.split("\n\n");
-    let range_list: Vec<_> = splitted.next().unwrap()
+fn parse(input: &str) -> Result<Input, ParseError> {
+    let source = Source::new(input);
+    let (ranges, ingredients) = source.split_once(input, "\n\n")?;
+    let range_list: Vec<_> = ranges
         .split("\n")
         .map(|line| {
-            let mut splitted = line.split('-');
-            let left: usize = splitted.next().unwrap().parse().unwrap();
-            let right: usize = splitted.next().unwrap().parse().unwrap();
-            left..=right
+            let (left, right) = source.split_once(line, "-")?;
+            Ok(source.parse(left)?..=source.parse(right)?)
         })
-        .collect();
-    let ingredients: Vec<usize> = splitted.next().unwrap()
+        .collect::<Result<_, ParseError>>()?;
+    let ingredients: Vec<usize> = ingredients
         .split("\n")
         .filter(|line| !line.is_empty())
-        .map(|n| n.parse().unwrap())
-        .collect();
+        .map(|n| source.parse(n))
+        .collect::<Result<_, _>>()?;
 
-    Input { range_list, ingredients }
+    Ok(Input { range_list, ingredients })
 }
 
 #[cfg(test)]
@@ -82,13 +83,13 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3));
+        assert_eq!(result, Ok(3));
     }
 
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(14));
+        assert_eq!(result, Ok(14));
     }
 
     #[test]
@@ -1,4 +1,6 @@
-advent_of_code::solution!(6);
+use advent_of_code::template::parse::{ParseError, Source};
+
+advent_of_code::solution!(6, raw);
 
 #[derive(Debug)]
 struct Input {
@@ -16,13 +18,13 @@ struct Operation {
     numbers: Vec<usize>,
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let input = parse(input);
+pub fn part_one(input: &str) -> Result<usize, ParseError> {
+    let input = parse(input)?;
     let table = transform_to_table(&input);
 
     let res = table.iter().map(|operation| calculate(operation)).sum();
 
-    Some(res)
+    Ok(res)
 }
 
 pub fn part_two(input: &str) -> Option<usize> {
@@ -67,18 +69,26 @@ fn calculate(operation: &Operation) -> usize {
     })
 }
 
-fn parse(input: &str) -> Input {
+fn parse(input: &str) -> Result<Input, ParseError> {
+    let source = Source::new(input);
     let lines: Vec<_> = input.split('\n').filter(|line| !line.is_empty()).collect();
+    let Some((operation_line, number_lines)) = lines.split_last() else {
+        return Err(source.error(input, "expected a line of operations"));
+    };
 
-    let numbers = lines[0..lines.len()-1].iter().map(|line| {
-        line.split_whitespace().filter(|c| !c.is_empty()).map(|n| n.parse().unwrap()).collect()
-    }).collect();
-    let operations = lines[lines.len()-1].split_whitespace().map(|c| c.chars().next().unwrap()).collect();
+    let numbers = number_lines.iter().map(|line| {
+        line.split_whitespace().filter(|c| !c.is_empty()).map(|n| source.parse(n)).collect()
+    }).collect::<Result<_, _>>()?;
+    let operations = operation_line.split_whitespace().map(|c| match c {
+        "+" => Ok('+'),
+        "*" => Ok('*'),
+        _ => Err(source.error(c, "expected an operation, `+` or `*`")),
+    }).collect::<Result<_, _>>()?;
 
-    Input{
+    Ok(Input{
         operations,
         numbers,
-    }
+    })
 }
 
 fn get_table(input: &Input2) -> MathTable {
@@ -132,13 +142,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4277556));
+        let result = part_one(&advent_of_code::template::read_file_raw("examples", DAY));
+        assert_eq!(result, Ok(4277556));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file_raw("examples", DAY));
         assert_eq!(result, Some(3263827));
     }
 }
@@ -1,9 +1,10 @@
 
-use std::collections::{HashMap, HashSet};
+use std::{collections::{HashMap, HashSet}, error::Error};
 
+use advent_of_code::template::parse::{ParseError, Source};
 use vecmath::Vector3;
 
-advent_of_code::solution!(8);
+advent_of_code::solution!(8, params { connections: usize = 1000 });
 
 #[derive(Debug)]
 struct Graph {
@@ -74,19 +75,15 @@ impl Graph {
     }
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    part_one_n(input, 1000)
-}
-
-fn part_one_n(input: &str, n: usize) -> Option<usize> {
-    let input = parse(input);
+pub fn part_one(input: &str, params: &Params) -> Result<usize, ParseError> {
+    let input = parse(input)?;
     let mut distances = calculate_distance(&input);
     let mut graph = Graph::new(&input);
 
     distances.sort_by(|a, b| a.2.partial_cmp(&b.2).unwrap());
     distances.reverse();
 
-    for _ in 0..n {
+    for _ in 0..params.connections {
         let next = distances.pop().unwrap();
 
         graph.connect(next.0, next.1);
@@ -98,15 +95,15 @@ fn part_one_n(input: &str, n: usize) -> Option<usize> {
 
     let res = calculate_product(&graphs);
 
-    Some(res)
+    Ok(res)
 }
 
 fn calculate_product(graphs: &Vec<HashSet<Vector3<usize>>>) -> usize {
     graphs[0..3].iter().map(|g| g.len()).product()
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let input = parse(input);
+pub fn part_two(input: &str, _params: &Params) -> Result<usize, Box<dyn Error>> {
+    let input = parse(input)?;
     let mut distances = calculate_distance(&input);
     let mut graph = Graph::new(&input);
 
@@ -136,17 +133,19 @@ pub fn part_two(input: &str) -> Option<usize> {
         }
     };
 
-    history.map(|h| h.0[0] * h.1[0])
+    history.map(|h| h.0[0] * h.1[0]).ok_or("the boxes are never connected to a single circuit".into())
 }
 
 type Input = HashSet<Vector3<usize>>;
 
-fn parse(input: &str) -> Input {
+fn parse(input: &str) -> Result<Input, ParseError> {
+    let source = Source::new(input);
     input.split('\n')
         .filter(|line| !line.is_empty())
         .map(|line| {
-            let mut numbers = line.split(',').map(|number| number.parse().unwrap());
-            [numbers.next().unwrap(), numbers.next().unwrap(), numbers.next().unwrap()]
+            let (x, rest) = source.split_once(line, ",")?;
+            let (y, z) = source.split_once(rest, ",")?;
+            Ok([source.parse(x)?, source.parse(y)?, source.parse(z)?])
         }).collect()
 }
 
@@ -178,14 +177,14 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one_n(&advent_of_code::template::read_file("examples", DAY), 10);
-        assert_eq!(result, Some(40));
+        let result = part_one(&advent_of_code::template::read_file("examples", DAY), &Params::example(1));
+        assert_eq!(result, Ok(40));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(25272));
+        let result = part_two(&advent_of_code::template::read_file("examples", DAY), &Params::example(1));
+        assert_eq!(result.unwrap(), 25272);
     }
 
     #[test]
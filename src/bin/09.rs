@@ -1,5 +1,6 @@
 use std::{collections::{HashMap, HashSet}, fmt::Display};
 
+use advent_of_code::template::parse::{ParseError, Source};
 use vecmath::Vector2;
 
 advent_of_code::solution!(9);
@@ -130,14 +131,14 @@ impl Display for Map {
     }
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let input = parse(input);
+pub fn part_one(input: &str) -> Result<usize, ParseError> {
+    let input = parse(input)?;
     let sizes = get_all_sizes(&input);
-    Some(sizes.iter().map(|r| r.2).max().unwrap())
+    Ok(sizes.iter().map(|r| r.2).max().unwrap())
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let input = parse(input);
+pub fn part_two(input: &str) -> Result<usize, ParseError> {
+    let input = parse(input)?;
     let (xs, ys) = (input.iter().map(|i| i[0]).collect(), input.iter().map(|i| i[1]).collect());
     let (map_x, map_y) = (create_number_map(xs), create_number_map(ys));
 
@@ -161,17 +162,16 @@ pub fn part_two(input: &str) -> Option<usize> {
 
     // println!("{}", map);
 
-    Some(max.2)
+    Ok(max.2)
 }
 
-fn parse(input: &str) -> Vec<Vector2<usize>> {
+fn parse(input: &str) -> Result<Vec<Vector2<usize>>, ParseError> {
+    let source = Source::new(input);
     input.split('\n')
         .filter(|line| !line.is_empty())
         .map(|line| {
-            let numbers: Vec<usize> = line.split(',')
-                .map(|n| n.parse().unwrap())
-                .collect();
-            [*numbers.get(0).unwrap(), *numbers.get(1).unwrap()]
+            let (x, y) = source.split_once(line, ",")?;
+            Ok([source.parse(x)?, source.parse(y)?])
         }).collect()
 }
 
@@ -208,12 +208,12 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(50));
+        assert_eq!(result, Ok(50));
     }
 
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(24));
+        assert_eq!(result, Ok(24));
     }
 }
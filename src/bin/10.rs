@@ -1,31 +1,30 @@
-#![feature(trim_prefix_suffix)]
-
 use std::{collections::{HashSet, LinkedList}, rc::Rc};
 
+use advent_of_code::template::parse::{ParseError, Source};
 use z3::{Optimize, SatResult, Solver, ast::Int};
 
 advent_of_code::solution!(10);
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let input = parse(input);
+pub fn part_one(input: &str) -> Result<usize, ParseError> {
+    let input = parse(input)?;
 
     let sum = input.iter().enumerate().map(|(i, machine)| {
         println!("{}/{}", i, input.len());
         calculate_fewest(machine)
     }).sum();
 
-    Some(sum)
+    Ok(sum)
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let input = parse(input);
+pub fn part_two(input: &str) -> Result<usize, ParseError> {
+    let input = parse(input)?;
 
     let sum = input.iter().enumerate().map(|(i, machine)| {
         println!("{}/{}", i, input.len());
         calculate_fewest_joltage(machine)
     }).sum();
 
-    Some(sum)
+    Ok(sum)
 }
 
 struct StackEntry {
@@ -113,43 +112,46 @@ fn apply_button(button: &HashSet<usize>, lights: &Vec<bool>) -> Vec<bool> {
     }).collect()
 }
 
-fn parse(input: &str) -> Input {
+fn parse(input: &str) -> Result<Input, ParseError> {
+    let source = Source::new(input);
     input.split('\n')
         .filter(|line| !line.is_empty())
         .map(|line| {
-            let line: Vec<_> = line.split(" ").collect();
-            let lights = line[0]
-                .trim_prefix('[')
-                .trim_suffix(']')
+            let tokens: Vec<_> = line.split(" ").collect();
+            let [lights, buttons @ .., joltage] = tokens.as_slice() else {
+                return Err(source.error(line, "expected lights, buttons and joltages"));
+            };
+            let lights = bracketed(&source, lights, '[', ']')?
                 .chars()
                 .map(|c| c == '#')
                 .collect();
-            let buttons = line[1..line.iter().len()-1]
+            let buttons = buttons
                 .iter()
-                .map(|button| {
-                    button.trim_prefix('(')
-                    .trim_suffix(')')
-                    .split(',')
-                    .map(|n| n.parse().unwrap())
-                    .collect()
-                })
-                .collect();
-            let joltage = line[line.len()-1]
-                .trim_prefix('{')
-                .trim_suffix('}')
-                .split(',')
-                .map(|n| n.parse().unwrap())
-                .collect();
+                .map(|button| numbers(&source, bracketed(&source, button, '(', ')')?))
+                .collect::<Result<_, _>>()?;
+            let joltage = numbers(&source, bracketed(&source, joltage, '{', '}')?)?;
 
-            Machine {
+            Ok(Machine {
                 lights,
                 buttons,
                 joltage
-            }
+            })
         })
         .collect()
 }
 
+fn bracketed<'a>(source: &Source, token: &'a str, open: char, close: char) -> Result<&'a str, ParseError> {
+    token.strip_prefix(open)
+        .and_then(|token| token.strip_suffix(close))
+        .ok_or_else(|| source.error(token, format!("expected a list in `{open}{close}`")))
+}
+
+fn numbers<C: FromIterator<usize>>(source: &Source, list: &str) -> Result<C, ParseError> {
+    list.split(',')
+        .map(|n| source.parse(n))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -157,13 +159,13 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(7));
+        assert_eq!(result, Ok(7));
     }
 
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(33));
+        assert_eq!(result, Ok(33));
     }
 
     #[test]
@@ -189,7 +191,7 @@ mod tests {
                 ),
                 joltage: vec!(3, 5, 4, 7)
             }),
-            parse(&"[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}")
+            parse(&"[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}").unwrap()
         )
     }
 }
@@ -1,36 +1,38 @@
 use std::collections::{HashMap, HashSet};
 
+use advent_of_code::template::parse::{ParseError, Source};
+
 advent_of_code::solution!(11);
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let input = parse(input);
+pub fn part_one(input: &str) -> Result<usize, ParseError> {
+    let input = parse(input)?;
 
     let count = count_paths("you", "out", &input);
 
-    Some(count)
+    Ok(count)
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let input = parse(input);
+pub fn part_two(input: &str) -> Result<usize, ParseError> {
+    let input = parse(input)?;
 
     let conditions = ["dac".to_string(), "fft".to_string()];
 
     let count = count_paths_pass_through("svr", "out", &input, &mut HashSet::from(conditions), &mut HashMap::new());
 
-    Some(count)
+    Ok(count)
 }
 
 type Input = HashMap<String, HashSet<String>>;
 
-fn parse(input: &str) -> Input {
+fn parse(input: &str) -> Result<Input, ParseError> {
+    let source = Source::new(input);
     input.split('\n')
         .filter(|line| !line.is_empty())
         .map(|line| {
-            let mut left_right = line.split(": ");
-            let left = left_right.next().unwrap().to_string();
-            let right = left_right.next().unwrap().split(" ").map(|s| s.to_string()).collect();
+            let (left, right) = source.split_once(line, ": ")?;
+            let right = right.split(" ").map(|s| s.to_string()).collect();
 
-            (left, right)
+            Ok((left.to_string(), right))
         })
         .collect()
 }
@@ -75,12 +77,12 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(5));
+        assert_eq!(result, Ok(5));
     }
 
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file_part("examples", DAY, 2));
-        assert_eq!(result, Some(2));
+        assert_eq!(result, Ok(2));
     }
 }
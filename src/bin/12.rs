@@ -1,12 +1,13 @@
 use std::collections::{HashMap, HashSet};
 
 use advent_of_code::template::commands::all;
+use advent_of_code::template::parse::{ParseError, Source};
 use vecmath::{Vector2, vec2_add};
 
 advent_of_code::solution!(12);
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let input = parse(input);
+pub fn part_one(input: &str) -> Result<usize, ParseError> {
+    let input = parse(input)?;
 
     let res = input.trees.iter().filter(|tree| {
         let pixels_needed: usize = tree.indexes.iter().enumerate().map(|(i, n)| n* input.presents.get(&i).unwrap().len()).sum();
@@ -20,7 +21,7 @@ pub fn part_one(input: &str) -> Option<usize> {
         number_of_shapes <= tree.size[0]/3 * tree.size[1]/3
     }).count();
 
-    Some(res)
+    Ok(res)
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
@@ -39,24 +40,26 @@ struct Input {
     trees: Vec<Tree>,
 }
 
-fn parse(input: &str) -> Input {
+fn parse(input: &str) -> Result<Input, ParseError> {
+    let source = Source::new(input);
     let presents: Vec<_> = input.split("\n\n").collect();
     let trees = presents[presents.len()-1].split('\n')
         .filter(|line| !line.is_empty())
         .map(|line| {
-            let mut left_right = line.split(": ");
-            let mut left = left_right.next().unwrap().split('x').map(|n| n.parse().unwrap());
-            let right = left_right.next().unwrap().split(' ').map(|n| n.parse().unwrap()).collect();
+            let (left, right) = source.split_once(line, ": ")?;
+            let (width, height) = source.split_once(left, "x")?;
+            let right = right.split(' ').map(|n| source.parse(n)).collect::<Result<_, _>>()?;
 
-            Tree {
+            Ok(Tree {
                 indexes: right,
-                size: [left.next().unwrap(), left.next().unwrap()],
-            }
+                size: [source.parse(width)?, source.parse(height)?],
+            })
         })
-        .collect();
+        .collect::<Result<_, _>>()?;
     let presents = presents[0..presents.len()-1].iter().map(|present| {
         let mut left_right = present.split('\n');
-        let n = left_right.next().unwrap().trim_end_matches(":").parse().unwrap();
+        let index = left_right.next().unwrap_or_default();
+        let n = source.parse(index.strip_suffix(':').ok_or_else(|| source.error(index, "expected an index like `0:`"))?)?;
         let grid = left_right.enumerate().map(|(y, line)| {
             line.chars().enumerate().filter_map(|(x, c)| {
                 if c != '#' {
@@ -65,13 +68,13 @@ fn parse(input: &str) -> Input {
                 Some([x, y])
             }).collect::<Vec<_>>()
         }).flatten().collect();
-        (n, grid)
-    }).collect();
+        Ok((n, grid))
+    }).collect::<Result<_, _>>()?;
 
-    Input {
+    Ok(Input {
         presents,
         trees,
-    }
+    })
 }
 
 #[cfg(test)]
@@ -83,7 +86,7 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(2));
+        assert_eq!(result, Ok(2));
     }
 
     #[test]
@@ -120,7 +123,7 @@ mod tests {
 
 39x38: 42 38 49 36 29 33"#;
 
-        assert_eq!(input, parse(&str));
+        assert_eq!(input, parse(&str).unwrap());
     }
 
     #[test]
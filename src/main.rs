@@ -1,32 +1,54 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::Puzzle;
+use advent_of_code::template::commands::{
+    all, auth, crypt, download, journal, leaderboard, migrate, read, refresh, scaffold, solve,
+    time, verify,
+};
 use args::{AppArguments, parse};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
-#[cfg(feature = "today")]
-use std::process;
+use advent_of_code::template::commands::today;
 
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::leaderboard::SortBy;
+    use advent_of_code::template::{Day, DayFromStrError, all_days};
     use std::process;
 
     pub enum AppArguments {
+        AuthCheck,
+        Decrypt,
+        Encrypt,
         Download {
-            day: Day,
+            days: Vec<Day>,
+            overwrite: bool,
+        },
+        Journal,
+        Leaderboard {
+            id: String,
+            file: Option<String>,
+            sort: SortBy,
         },
+        Migrate,
         Read {
             day: Day,
+            part: Option<u8>,
+        },
+        Refresh {
+            day: Day,
         },
         Scaffold {
             day: Day,
+            template: Option<String>,
             download: bool,
             overwrite: bool,
+            pick: bool,
         },
         Solve {
             day: Day,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            all_inputs: bool,
+            example: Option<usize>,
         },
         All {
             release: bool,
@@ -36,8 +58,13 @@ mod args {
             day: Option<Day>,
             store: bool,
         },
+        Verify {
+            day: Option<Day>,
+        },
         #[cfg(feature = "today")]
-        Today,
+        Today {
+            wait: bool,
+        },
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
@@ -57,25 +84,63 @@ mod args {
                     store,
                 }
             }
-            Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+            Some("auth") => match args.subcommand()?.as_deref() {
+                Some("check") => AppArguments::AuthCheck,
+                _ => {
+                    eprintln!("Unknown auth command. Usage: cargo auth check");
+                    process::exit(1);
+                }
+            },
+            Some("decrypt") => AppArguments::Decrypt,
+            Some("encrypt") => AppArguments::Encrypt,
+            Some("download") => {
+                let overwrite = args.contains("--overwrite");
+                let days = if args.contains("--all") {
+                    all_days().collect()
+                } else {
+                    args.free_from_fn(parse_days)?
+                };
+
+                AppArguments::Download { days, overwrite }
+            }
+            Some("journal") => AppArguments::Journal,
+            Some("leaderboard") => AppArguments::Leaderboard {
+                file: args.opt_value_from_str("--file")?,
+                sort: args
+                    .opt_value_from_str("--sort")?
+                    .unwrap_or(SortBy::LocalScore),
+                id: args.free_from_str()?,
             },
+            Some("migrate") => AppArguments::Migrate,
             Some("read") => AppArguments::Read {
                 day: args.free_from_str()?,
+                part: args.opt_value_from_str("--part")?,
+            },
+            Some("refresh") => AppArguments::Refresh {
+                day: args.free_from_str()?,
             },
             Some("scaffold") => AppArguments::Scaffold {
                 day: args.free_from_str()?,
+                template: args.opt_value_from_str("--template")?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                pick: args.contains("--pick"),
             },
             Some("solve") => AppArguments::Solve {
                 day: args.free_from_str()?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 dhat: args.contains("--dhat"),
+                all_inputs: args.contains("--all-inputs"),
+                example: args.opt_value_from_str("--example")?,
+            },
+            Some("verify") => AppArguments::Verify {
+                day: args.opt_free_from_str()?,
             },
             #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
+            Some("today") => AppArguments::Today {
+                wait: args.contains("--wait"),
+            },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -93,6 +158,20 @@ mod args {
 
         Ok(app_args)
     }
+
+    /// Parses a single day (`5`) or an inclusive range of days (`1-5`).
+    fn parse_days(s: &str) -> Result<Vec<Day>, DayFromStrError> {
+        match s.split_once('-') {
+            Some((start, end)) => {
+                let start: Day = start.parse()?;
+                let end: Day = end.parse()?;
+                Ok(all_days()
+                    .filter(|day| *day >= start && *day <= end)
+                    .collect())
+            }
+            None => Ok(vec![s.parse()?]),
+        }
+    }
 }
 
 fn main() {
@@ -104,41 +183,52 @@ fn main() {
         Ok(args) => match args {
             AppArguments::All { release } => all::handle(release),
             AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+            AppArguments::AuthCheck => auth::handle_check(),
+            AppArguments::Decrypt => crypt::handle_decrypt(),
+            AppArguments::Encrypt => crypt::handle_encrypt(),
+            AppArguments::Download { days, overwrite } => {
+                let puzzles: Vec<Puzzle> = days.into_iter().map(Puzzle::current).collect();
+                download::handle(&puzzles, overwrite);
+            }
+            AppArguments::Journal => journal::handle(),
+            AppArguments::Leaderboard { id, file, sort } => {
+                leaderboard::handle(&id, file.as_deref(), sort);
+            }
+            AppArguments::Migrate => migrate::handle(),
+            AppArguments::Read { day, part } => read::handle(Puzzle::current(day), part),
+            AppArguments::Refresh { day } => refresh::handle(Puzzle::current(day)),
             AppArguments::Scaffold {
                 day,
+                template,
                 download,
                 overwrite,
+                pick,
             } => {
-                scaffold::handle(day, overwrite);
+                let puzzle = Puzzle::current(day);
+                // download first, so the template can use the title of the puzzle.
                 if download {
-                    download::handle(day);
+                    scaffold::create_data_dirs(puzzle);
+                    download::handle(&[puzzle], overwrite);
                 }
+                scaffold::handle(puzzle, template.as_deref(), overwrite, pick);
             }
             AppArguments::Solve {
                 day,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
-            #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
-                    }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
-                    }
-                };
+                all_inputs,
+                example,
+            } => {
+                if all_inputs {
+                    solve::handle_all_inputs(day, release);
+                } else {
+                    solve::handle(Puzzle::current(day), release, dhat, submit, example);
+                }
             }
+            AppArguments::Verify { day } => verify::handle(day),
+            #[cfg(feature = "today")]
+            AppArguments::Today { wait } => today::handle(wait),
         },
     };
 }
@@ -0,0 +1,248 @@
+use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Puzzle, encryption, layout, puzzle};
+
+const ANSWERS_FILE: &str = "answers.json";
+
+/// Represents the answers accepted by the website for a single day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Answer {
+    pub day: Day,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl Answer {
+    pub fn part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Represents the accepted answers for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub data: Vec<Answer>,
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file of a year.
+    pub fn store_file(&self, year: u16) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(layout::store_path(year, ANSWERS_FILE))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file of a year. If not present, returns empty answers.
+    pub fn read_from_file(year: u16) -> Self {
+        fs::read_to_string(layout::store_path(year, ANSWERS_FILE))
+            .map_err(|x| x.to_string())
+            .and_then(Answers::try_from)
+            .unwrap_or_default()
+    }
+
+    /// Reads the stored answers, completed with the answers listed in downloaded puzzle descriptions.
+    pub fn read_all() -> Self {
+        let year = layout::active_year();
+        let mut answers = Self::read_from_file(year);
+
+        for day in crate::template::all_days() {
+            let Ok(md) = encryption::read_to_string(Puzzle::new(year, day).puzzle_path()) else {
+                continue;
+            };
+            for (part, answer) in (1..=2).zip(puzzle::accepted_answers(&md)) {
+                if answers.get(day).part(part).is_none() {
+                    answers.set(day, part, answer);
+                }
+            }
+        }
+
+        answers
+    }
+
+    /// Records an accepted answer in the answers file of the puzzle's year.
+    pub fn record(puzzle: Puzzle, part: u8, answer: &str) -> Result<(), Error> {
+        let mut answers = Self::read_from_file(puzzle.year);
+        answers.set(puzzle.day, part, Some(answer.to_string()));
+        answers.store_file(puzzle.year)
+    }
+
+    /// Returns the answers for a day, which are empty if none was accepted yet.
+    pub fn get(&self, day: Day) -> Answer {
+        self.data
+            .iter()
+            .find(|answer| answer.day == day)
+            .cloned()
+            .unwrap_or(Answer {
+                day,
+                part_1: None,
+                part_2: None,
+            })
+    }
+
+    fn set(&mut self, day: Day, part: u8, value: Option<String>) {
+        if value.is_none() {
+            return;
+        }
+
+        let index = match self.data.iter().position(|answer| answer.day == day) {
+            Some(index) => index,
+            None => {
+                self.data.push(self.get(day));
+                self.data.sort_unstable_by_key(|answer| answer.day);
+                self.data
+                    .iter()
+                    .position(|answer| answer.day == day)
+                    .unwrap()
+            }
+        };
+
+        match part {
+            1 => self.data[index].part_1 = value,
+            2 => self.data[index].part_2 = value,
+            _ => {}
+        }
+    }
+
+    /// Counts the stars earned, i.e. the number of accepted answers.
+    pub fn stars(&self) -> usize {
+        self.data
+            .iter()
+            .map(|answer| {
+                usize::from(answer.part_1.is_some()) + usize::from(answer.part_2.is_some())
+            })
+            .sum()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            data: json_data
+                .iter()
+                .map(Answer::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answer> for JsonValue {
+    fn from(value: &Answer) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+
+        for (key, part) in [("part_1", &value.part_1), ("part_2", &value.part_2)] {
+            map.insert(
+                key.into(),
+                part.clone().map_or(JsonValue::Null, JsonValue::String),
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Answer {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answer to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::parse_any(day))
+            .ok_or("Expected answer.day to be a Day struct.")?;
+
+        let part = |key: &str| {
+            json.get(key)
+                .map(|v| if v.is_null() { None } else { v.get::<String>() })
+                .ok_or(format!("Expected answer.{key} to be null or string."))
+        };
+
+        Ok(Answer {
+            day,
+            part_1: part("part_1")?.cloned(),
+            part_2: part("part_2")?.cloned(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Answers;
+    use crate::day;
+
+    #[test]
+    fn handles_json_answers() {
+        let json = r#"{ "data": [{ "day": "01", "part_1": "42", "part_2": null }] }"#.to_string();
+        let answers = Answers::try_from(json).unwrap();
+        let answer = answers.get(day!(1));
+        assert_eq!(answer.part(1), Some("42"));
+        assert_eq!(answer.part(2), None);
+        assert_eq!(answers.stars(), 1);
+    }
+
+    #[test]
+    fn sets_answers_in_day_order() {
+        let mut answers = Answers::default();
+        answers.set(day!(3), 1, Some("3".into()));
+        answers.set(day!(1), 2, Some("1".into()));
+        answers.set(day!(3), 2, Some("33".into()));
+        answers.set(day!(2), 1, None);
+
+        assert_eq!(answers.data.len(), 2);
+        assert_eq!(answers.data[0].day, day!(1));
+        assert_eq!(answers.get(day!(3)).part(2), Some("33"));
+        assert_eq!(answers.stars(), 3);
+    }
+
+    #[test]
+    fn round_trips_json() {
+        let mut answers = Answers::default();
+        answers.set(day!(5), 1, Some("12".into()));
+
+        let json = tinyjson::JsonValue::from(answers).stringify().unwrap();
+        let answers = Answers::try_from(json).unwrap();
+        assert_eq!(answers.get(day!(5)).part(1), Some("12"));
+    }
+}
@@ -0,0 +1,588 @@
+/// Native client for the Advent of Code website.
+/// Used in place of the "aoc-cli" command-line when a session cookie is available.
+use std::{
+    cell::Cell,
+    env,
+    fmt::Display,
+    io, thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use crate::template::http_cache::HttpCache;
+use crate::template::integrity::Problem;
+use crate::template::session::{Session, SessionProvider, SessionSource};
+use crate::template::{Puzzle, aoc_cli, layout, markdown};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Minimum delay between two requests, to be polite to the website.
+const DEFAULT_THROTTLE: Duration = Duration::from_secs(1);
+
+/// How long a fetched private leaderboard is served from the cache.
+const LEADERBOARD_TTL: Duration = Duration::from_secs(15 * 60);
+
+const USER_AGENT: &str = concat!(
+    "github.com/Fgdou/AdventOfCode2025 via ",
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION")
+);
+
+#[derive(Debug)]
+pub enum AocClientError {
+    NoSession,
+    SessionExpired(SessionSource),
+    Status(u16, String),
+    Transport(String),
+    InvalidInput(Problem),
+    IO(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::NoSession => write!(
+                f,
+                "no session cookie found. Set AOC_SESSION, create ~/.adventofcode.session or install aoc-cli."
+            ),
+            AocClientError::SessionExpired(source) => write!(
+                f,
+                "the session cookie from {source} has expired or is invalid. \
+                Log in to adventofcode.com, copy the new `session` cookie into {source} \
+                and run `cargo auth check` to verify it."
+            ),
+            AocClientError::Status(status, _) => {
+                write!(f, "server responded with status {status}.")
+            }
+            AocClientError::Transport(e) => write!(f, "request failed: {e}"),
+            AocClientError::InvalidInput(problem) => write!(f, "{problem}"),
+            AocClientError::IO(e) => write!(f, "could not read response: {e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+/// The verdict of the website for a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    TooSoon,
+    WrongLevel,
+    Unknown,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub verdict: Verdict,
+    pub message: String,
+}
+
+impl Display for Submission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let icon = match self.verdict {
+            Verdict::Correct => "⭐",
+            Verdict::Incorrect => "❌",
+            Verdict::TooSoon => "⏳",
+            Verdict::WrongLevel | Verdict::Unknown => "❔",
+        };
+        write!(f, "{icon} {}", self.message)
+    }
+}
+
+/// A puzzle description converted to markdown.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Description {
+    pub markdown: String,
+    pub fetched_at: SystemTime,
+    pub from_cache: bool,
+}
+
+pub struct AocClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: Session,
+    year: u16,
+    throttle: Duration,
+    last_request: Cell<Option<Instant>>,
+    cache: Option<HttpCache>,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: Session, year: u16) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            year,
+            throttle: Duration::ZERO,
+            last_request: Cell::new(None),
+            cache: None,
+        }
+    }
+
+    /// Waits at least `throttle` between two requests.
+    #[must_use]
+    pub fn with_throttle(mut self, throttle: Duration) -> Self {
+        self.throttle = throttle;
+        self
+    }
+
+    /// Serves puzzle pages from `cache` when present, and stores fetched pages in it.
+    #[must_use]
+    pub fn with_cache(mut self, cache: HttpCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Creates a client from the environment:
+    ///  1. the base url is read from `AOC_BASE_URL`, defaulting to the official website.
+    ///  2. the session cookie is looked up by the default [`SessionProvider`].
+    ///  3. the year is the [active year](crate::template::layout::active_year).
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let session = SessionProvider::default()
+            .find()
+            .ok_or(AocClientError::NoSession)?;
+        let year = layout::active_year();
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Ok(Self::new(&base_url, session, year)
+            .with_throttle(DEFAULT_THROTTLE)
+            .with_cache(HttpCache::default()))
+    }
+
+    pub fn session(&self) -> &Session {
+        &self.session
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Validates the session cookie by loading the calendar page.
+    /// Returns the name of the logged in user.
+    pub fn check_session(&self) -> Result<String, AocClientError> {
+        let html = self.get(&format!("/{}", self.year))?;
+
+        html.split_once("<div class=\"user\">")
+            .map(|(_, rest)| {
+                let user = rest.split('<').next().unwrap_or_default();
+                markdown::from_html(user).trim().to_string()
+            })
+            .ok_or_else(|| self.expired())
+    }
+
+    /// Fetches the input of a puzzle.
+    pub fn input(&self, puzzle: Puzzle) -> Result<String, AocClientError> {
+        self.get(&format!(
+            "/{}/day/{}/input",
+            puzzle.year,
+            puzzle.day.into_inner()
+        ))
+    }
+
+    /// Returns the description of a puzzle, served from the cache if possible.
+    pub fn puzzle(&self, puzzle: Puzzle) -> Result<Description, AocClientError> {
+        let cached = self
+            .cache
+            .as_ref()
+            .and_then(|cache| cache.get(&puzzle_path(puzzle)));
+
+        match cached {
+            Some(page) => Ok(Description {
+                markdown: markdown::from_puzzle_html(&page.body),
+                fetched_at: page.fetched_at,
+                from_cache: true,
+            }),
+            None => self.refresh_puzzle(puzzle),
+        }
+    }
+
+    /// Fetches the description of a puzzle, bypassing and updating the cache.
+    pub fn refresh_puzzle(&self, puzzle: Puzzle) -> Result<Description, AocClientError> {
+        let path = puzzle_path(puzzle);
+        let html = self.get(&path)?;
+        let fetched_at = SystemTime::now();
+
+        if let Some(cache) = &self.cache {
+            cache.put(&path, &html, fetched_at)?;
+        }
+
+        Ok(Description {
+            markdown: markdown::from_puzzle_html(&html),
+            fetched_at,
+            from_cache: false,
+        })
+    }
+
+    /// Returns the JSON of a private leaderboard.
+    /// The website asks to not fetch leaderboards more than once every 15 minutes, so recent
+    /// responses are served from the cache.
+    pub fn leaderboard(&self, id: &str) -> Result<String, AocClientError> {
+        let path = format!("/{}/leaderboard/private/view/{id}.json", self.year);
+
+        let cached = self.cache.as_ref().and_then(|cache| cache.get(&path));
+        if let Some(page) = cached
+            && page
+                .fetched_at
+                .elapsed()
+                .is_ok_and(|age| age < LEADERBOARD_TTL)
+        {
+            return Ok(page.body);
+        }
+
+        let json = self.get(&path)?;
+        // an expired session is redirected to the login page instead of returning JSON.
+        if !json.trim_start().starts_with('{') {
+            return Err(self.expired());
+        }
+
+        if let Some(cache) = &self.cache {
+            cache.put(&path, &json, SystemTime::now())?;
+        }
+
+        Ok(json)
+    }
+
+    /// Submits an answer for one part of a puzzle.
+    pub fn submit(
+        &self,
+        puzzle: Puzzle,
+        part: u8,
+        answer: &str,
+    ) -> Result<Submission, AocClientError> {
+        let url = format!("{}{}/answer", self.base_url, puzzle_path(puzzle));
+
+        self.wait_for_throttle();
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+
+        let html = self.read_response(response)?;
+        let submission = parse_submission(&html);
+
+        if submission.verdict == Verdict::Unknown && mentions_login(&submission.message) {
+            return Err(self.expired());
+        }
+
+        Ok(submission)
+    }
+
+    fn wait_for_throttle(&self) {
+        if let Some(last) = self.last_request.get() {
+            let elapsed = last.elapsed();
+            if elapsed < self.throttle {
+                thread::sleep(self.throttle - elapsed);
+            }
+        }
+        self.last_request.set(Some(Instant::now()));
+    }
+
+    fn get(&self, path: &str) -> Result<String, AocClientError> {
+        self.wait_for_throttle();
+        let url = format!("{}{path}", self.base_url);
+        let response = self.agent.get(&url).set("Cookie", &self.cookie()).call();
+        self.read_response(response)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session.cookie)
+    }
+
+    fn expired(&self) -> AocClientError {
+        AocClientError::SessionExpired(self.session.source.clone())
+    }
+
+    fn read_response(
+        &self,
+        response: Result<ureq::Response, ureq::Error>,
+    ) -> Result<String, AocClientError> {
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(status, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                // the website answers requests with a missing or expired cookie with 400 (or 500 for malformed ones).
+                if (status == 400 || status == 500) && mentions_login(&body) {
+                    Err(self.expired())
+                } else {
+                    Err(AocClientError::Status(status, body))
+                }
+            }
+            Err(ureq::Error::Transport(e)) => Err(AocClientError::Transport(e.to_string())),
+        }
+    }
+}
+
+/// The implementation used to talk to the website.
+pub enum Backend {
+    Native(AocClient),
+    AocCli,
+}
+
+/// Prefers the native client and falls back to aoc-cli when no session cookie is configured.
+pub fn backend() -> Result<Backend, AocClientError> {
+    match AocClient::from_env() {
+        Ok(client) => Ok(Backend::Native(client)),
+        Err(AocClientError::NoSession) if aoc_cli::check().is_ok() => Ok(Backend::AocCli),
+        Err(e) => Err(e),
+    }
+}
+
+fn puzzle_path(puzzle: Puzzle) -> String {
+    format!("/{}/day/{}", puzzle.year, puzzle.day.into_inner())
+}
+
+fn parse_submission(html: &str) -> Submission {
+    let message = markdown::from_html(
+        html.find("<article>")
+            .and_then(|start| {
+                html[start..]
+                    .find("</article>")
+                    .map(|end| &html[start..start + end])
+            })
+            .unwrap_or(html),
+    )
+    .trim()
+    .to_string();
+
+    let verdict = if message.contains("That's the right answer") {
+        Verdict::Correct
+    } else if message.contains("That's not the right answer") {
+        Verdict::Incorrect
+    } else if message.contains("You gave an answer too recently") {
+        Verdict::TooSoon
+    } else if message.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        Verdict::Unknown
+    };
+
+    Submission { verdict, message }
+}
+
+fn mentions_login(text: &str) -> bool {
+    let text = text.to_ascii_lowercase();
+    text.contains("log in") || text.contains("logged in")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{AocClient, AocClientError, Verdict};
+    use crate::template::http_cache::HttpCache;
+    use crate::template::mock_server::{MockRoute, MockServer};
+    use crate::template::session::{Session, SessionSource};
+    use crate::template::temp_dir::TempDir;
+    use crate::template::{Day, Puzzle};
+    use std::time::{Duration, Instant};
+
+    fn puzzle(day: u8) -> Puzzle {
+        Puzzle::new(2025, Day::new(day).unwrap())
+    }
+
+    fn client(server: &MockServer) -> AocClient {
+        let session = Session {
+            cookie: "abc".into(),
+            source: SessionSource::Env("AOC_SESSION".into()),
+        };
+        AocClient::new(&server.base_url, session, 2025)
+    }
+
+    #[test]
+    fn downloads_input_with_session_and_user_agent() {
+        let server = MockServer::start(vec![MockRoute::new(
+            "GET /2025/day/1/input",
+            200,
+            "1\n2\n3\n",
+        )]);
+        let client = client(&server);
+
+        assert_eq!(client.input(puzzle(1)).unwrap(), "1\n2\n3\n");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2025/day/1/input");
+        assert_eq!(requests[0].header("cookie"), Some("session=abc"));
+        assert!(
+            requests[0]
+                .header("user-agent")
+                .unwrap()
+                .contains("github.com")
+        );
+    }
+
+    #[test]
+    fn converts_puzzle_to_markdown() {
+        let server = MockServer::start(vec![MockRoute::new(
+            "GET /2025/day/2",
+            200,
+            "<main><article class=\"day-desc\"><h2>--- Day 2: Test ---</h2><p>Hi <em>there</em>.</p></article></main>",
+        )]);
+        let client = client(&server);
+
+        assert_eq!(
+            client.puzzle(puzzle(2)).unwrap().markdown,
+            "## --- Day 2: Test ---\n\nHi *there*.\n"
+        );
+    }
+
+    #[test]
+    fn submits_answers() {
+        let server = MockServer::start(vec![MockRoute::new(
+            "POST /2025/day/3/answer",
+            200,
+            "<main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main>",
+        )]);
+        let client = client(&server);
+
+        let submission = client.submit(puzzle(3), 1, "42").unwrap();
+        assert_eq!(submission.verdict, Verdict::Correct);
+
+        let requests = server.requests();
+        assert_eq!(requests[0].body, "level=1&answer=42");
+    }
+
+    #[test]
+    fn recognizes_wrong_answers() {
+        let server = MockServer::start(vec![MockRoute::new(
+            "POST /2025/day/3/answer",
+            200,
+            "<article><p>That's not the right answer; your answer is too low.</p></article>",
+        )]);
+        let client = client(&server);
+
+        let submission = client.submit(puzzle(3), 2, "1").unwrap();
+        assert_eq!(submission.verdict, Verdict::Incorrect);
+        assert!(submission.message.contains("too low"));
+    }
+
+    #[test]
+    fn surfaces_error_status() {
+        let server = MockServer::start(vec![]);
+        let client = client(&server);
+
+        assert!(matches!(
+            client.input(puzzle(4)),
+            Err(AocClientError::Status(404, _))
+        ));
+    }
+
+    #[test]
+    fn detects_expired_session_on_download() {
+        let server = MockServer::start(vec![MockRoute::new(
+            "GET /2025/day/5/input",
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+        )]);
+
+        let err = client(&server).input(puzzle(5)).unwrap_err();
+        assert!(matches!(err, AocClientError::SessionExpired(_)));
+        assert!(err.to_string().contains("environment variable AOC_SESSION"));
+    }
+
+    #[test]
+    fn detects_expired_session_on_submit() {
+        let server = MockServer::start(vec![MockRoute::new(
+            "POST /2025/day/5/answer",
+            200,
+            "<main><article><p>To play, please identify yourself via one of these services: [Log In]</p></article></main>",
+        )]);
+
+        assert!(matches!(
+            client(&server).submit(puzzle(5), 1, "1"),
+            Err(AocClientError::SessionExpired(_))
+        ));
+    }
+
+    #[test]
+    fn checks_session() {
+        let server = MockServer::start(vec![MockRoute::new(
+            "GET /2025",
+            200,
+            "<header><div class=\"user\">Felix <span class=\"star-count\">24*</span></div></header>",
+        )]);
+        assert_eq!(client(&server).check_session().unwrap(), "Felix");
+
+        let server = MockServer::start(vec![MockRoute::new(
+            "GET /2025",
+            200,
+            "<header><a href=\"/2025/auth/login\">[Log In]</a></header>",
+        )]);
+        assert!(matches!(
+            client(&server).check_session(),
+            Err(AocClientError::SessionExpired(_))
+        ));
+    }
+
+    #[test]
+    fn serves_puzzles_from_cache() {
+        let server = MockServer::start(vec![MockRoute::new(
+            "GET /2025/day/6",
+            200,
+            "<main><article><p>Cached.</p></article></main>",
+        )]);
+        let dir = TempDir::new("client-cache");
+        let client = client(&server).with_cache(HttpCache::new(dir.path()));
+
+        let first = client.puzzle(puzzle(6)).unwrap();
+        let second = client.puzzle(puzzle(6)).unwrap();
+
+        assert!(!first.from_cache);
+        assert!(second.from_cache);
+        assert_eq!(first.markdown, second.markdown);
+        assert_eq!(server.requests().len(), 1);
+
+        client.refresh_puzzle(puzzle(6)).unwrap();
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[test]
+    fn requests_year_of_puzzle() {
+        let server = MockServer::start(vec![MockRoute::new("GET /2024/day/3/input", 200, "3")]);
+        let puzzle = Puzzle::new(2024, Day::new(3).unwrap());
+
+        assert_eq!(client(&server).input(puzzle).unwrap(), "3");
+        assert_eq!(server.requests()[0].path, "/2024/day/3/input");
+    }
+
+    #[test]
+    fn throttles_requests() {
+        let server = MockServer::start(vec![MockRoute::new("GET /2025/day/7/input", 200, "7")]);
+        let client = client(&server).with_throttle(Duration::from_millis(200));
+
+        let timer = Instant::now();
+        client.input(puzzle(7)).unwrap();
+        client.input(puzzle(7)).unwrap();
+        assert!(timer.elapsed() >= Duration::from_millis(200));
+    }
+
+    #[test]
+    fn caches_leaderboards() {
+        let server = MockServer::start(vec![MockRoute::new(
+            "GET /2025/leaderboard/private/view/123.json",
+            200,
+            "{\"event\":\"2025\",\"members\":{}}",
+        )]);
+        let dir = TempDir::new("client-cache");
+        let client = client(&server).with_cache(HttpCache::new(dir.path()));
+
+        let first = client.leaderboard("123").unwrap();
+        let second = client.leaderboard("123").unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(server.requests().len(), 1);
+    }
+}
@@ -0,0 +1,136 @@
+//! Access to the current time that can be replaced in tests.
+use std::{
+    thread,
+    time::{Duration, SystemTime},
+};
+
+pub trait Clock {
+    fn now(&self) -> SystemTime;
+    fn sleep(&self, duration: Duration);
+}
+
+/// The clock of the operating system.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+/// Sleeps until `target`, calling `tick` with the remaining time about once per second.
+pub fn wait_until(clock: &impl Clock, target: SystemTime, mut tick: impl FnMut(Duration)) {
+    while let Ok(remaining) = target.duration_since(clock.now()) {
+        if remaining.is_zero() {
+            break;
+        }
+        tick(remaining);
+        clock.sleep(remaining.min(Duration::from_secs(1)));
+    }
+}
+
+/// Calls `f` until it succeeds, at most `attempts` times with `delay` in between.
+/// Returns the last error if no attempt succeeded.
+pub fn retry<T, E>(
+    clock: &impl Clock,
+    attempts: usize,
+    delay: Duration,
+    mut f: impl FnMut() -> Result<T, E>,
+) -> Result<T, E> {
+    let mut attempt = 1;
+    loop {
+        match f() {
+            Err(_) if attempt < attempts => {
+                attempt += 1;
+                clock.sleep(delay);
+            }
+            result => return result,
+        }
+    }
+}
+
+/// A clock that only advances when slept on.
+#[cfg(feature = "test_lib")]
+pub struct FakeClock {
+    now: std::cell::Cell<SystemTime>,
+}
+
+#[cfg(feature = "test_lib")]
+impl FakeClock {
+    pub fn new(now: SystemTime) -> Self {
+        Self {
+            now: std::cell::Cell::new(now),
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+impl Clock for FakeClock {
+    fn now(&self) -> SystemTime {
+        self.now.get()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        self.now.set(self.now.get() + duration);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Clock, FakeClock, retry, wait_until};
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn waits_until_target() {
+        let clock = FakeClock::new(UNIX_EPOCH);
+        let target = UNIX_EPOCH + Duration::from_millis(2500);
+        let mut ticks = vec![];
+
+        wait_until(&clock, target, |remaining| ticks.push(remaining));
+
+        assert_eq!(clock.now(), target);
+        assert_eq!(
+            ticks,
+            vec![
+                Duration::from_millis(2500),
+                Duration::from_millis(1500),
+                Duration::from_millis(500)
+            ]
+        );
+    }
+
+    #[test]
+    fn does_not_wait_for_past_targets() {
+        let clock = FakeClock::new(UNIX_EPOCH + Duration::from_secs(5));
+        wait_until(&clock, UNIX_EPOCH, |_| panic!("should not tick"));
+        assert_eq!(clock.now(), UNIX_EPOCH + Duration::from_secs(5));
+    }
+
+    #[test]
+    fn retries_until_success() {
+        let clock = FakeClock::new(UNIX_EPOCH);
+        let mut calls = 0;
+
+        let result: Result<u8, &str> = retry(&clock, 5, Duration::from_secs(2), || {
+            calls += 1;
+            if calls < 3 { Err("not ready") } else { Ok(42) }
+        });
+
+        assert_eq!(result, Ok(42));
+        assert_eq!(clock.now(), UNIX_EPOCH + Duration::from_secs(4));
+    }
+
+    #[test]
+    fn gives_up_after_attempts() {
+        let clock = FakeClock::new(UNIX_EPOCH);
+        let result: Result<(), &str> = retry(&clock, 3, Duration::from_secs(1), || Err("nope"));
+        assert_eq!(result, Err("nope"));
+        assert_eq!(clock.now(), UNIX_EPOCH + Duration::from_secs(2));
+    }
+}
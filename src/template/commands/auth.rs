@@ -0,0 +1,24 @@
+use std::process;
+
+use crate::template::aoc_client::AocClient;
+
+/// Validates the configured session cookie against the website.
+pub fn handle_check() {
+    let client = match AocClient::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let source = client.session().source.clone();
+
+    match client.check_session() {
+        Ok(user) => println!("🎄 Session cookie from {source} is valid. Logged in as {user}."),
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
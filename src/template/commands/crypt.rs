@@ -0,0 +1,116 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process,
+};
+
+use crate::template::encryption::{self, EncryptionError};
+use crate::template::layout::{self, Layout};
+
+/// Directories with files that must not be published, and the extension of these files.
+/// They are located in the data directory of every year.
+const PRIVATE_DIRS: [(&str, &str); 3] = [("inputs", "txt"), ("puzzles", "md"), ("examples", "txt")];
+
+/// Encrypts all plain inputs and puzzle descriptions, removing the plain files.
+pub fn handle_encrypt() {
+    let passphrase = passphrase();
+    let mut count = 0;
+
+    for path in private_files(false) {
+        let result = fs::read(&path).and_then(|data| {
+            fs::write(
+                encryption::encrypted_path(&path),
+                encryption::encrypt(&data, &passphrase),
+            )?;
+            fs::remove_file(&path)
+        });
+
+        if let Err(e) = result {
+            eprintln!("failed to encrypt \"{}\": {e}", path.display());
+            process::exit(1);
+        }
+        count += 1;
+    }
+
+    println!("🔒 Encrypted {count} file(s).");
+}
+
+/// Decrypts all encrypted inputs and puzzle descriptions, removing the encrypted files.
+pub fn handle_decrypt() {
+    let passphrase = passphrase();
+    let mut count = 0;
+
+    for path in private_files(true) {
+        let plain_path = path.with_extension("");
+        let result = fs::read(&path)
+            .map_err(EncryptionError::from)
+            .and_then(|data| encryption::decrypt(&data, &passphrase))
+            .and_then(|data| Ok(fs::write(&plain_path, data)?))
+            .and_then(|()| Ok(fs::remove_file(&path)?));
+
+        if let Err(e) = result {
+            eprintln!("failed to decrypt \"{}\": {e}", path.display());
+            process::exit(1);
+        }
+        count += 1;
+    }
+
+    println!("🔓 Decrypted {count} file(s).");
+}
+
+fn passphrase() -> String {
+    match encryption::read_passphrase() {
+        Ok(passphrase) => passphrase,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Lists the plain or encrypted private files, including named inputs in subdirectories.
+fn private_files(encrypted: bool) -> Vec<PathBuf> {
+    let data_dirs: Vec<PathBuf> = match Layout::detect() {
+        Layout::Flat => vec![Layout::Flat.data_dir(layout::active_year())],
+        layout @ Layout::YearQualified => layout::years()
+            .into_iter()
+            .map(|year| layout.data_dir(year))
+            .collect(),
+    };
+
+    let mut files = vec![];
+
+    for (data_dir, (dir, ext)) in data_dirs
+        .iter()
+        .flat_map(|data_dir| PRIVATE_DIRS.map(|private_dir| (data_dir, private_dir)))
+    {
+        let mut dir_files = vec![];
+        collect_files(&data_dir.join(dir), &mut dir_files);
+
+        files.extend(dir_files.into_iter().filter(|path| {
+            if encrypted {
+                path.to_string_lossy().ends_with(encryption::SUFFIX)
+            } else {
+                path.extension().is_some_and(|e| e == ext)
+            }
+        }));
+    }
+
+    files.sort();
+    files
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+    {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_files(&path, files);
+        } else {
+            files.push(path);
+        }
+    }
+}
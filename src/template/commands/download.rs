@@ -1,16 +1,139 @@
-use crate::template::{Day, aoc_cli};
-use std::process;
+use crate::template::aoc_client::{self, AocClient, AocClientError, Backend};
+use crate::template::encryption::{self, has_content};
+use crate::template::integrity::{self, Checksums, Problem};
+use crate::template::{Puzzle, aoc_cli};
+use std::time::{Duration, SystemTime};
+use std::{fs, process};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
-        process::exit(1);
+/// Downloads the input and puzzle for a set of puzzles.
+///
+/// Inputs that are already present and non-empty are kept unless `overwrite` is set.
+/// Days that are not unlocked yet are refused.
+pub fn handle(puzzles: &[Puzzle], overwrite: bool) {
+    let backend = match aoc_client::backend() {
+        Ok(backend) => backend,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let now = SystemTime::now();
+
+    for &puzzle in puzzles {
+        let day = puzzle.day;
+        let unlock_time = day.unlock_time(puzzle.year);
+        if let Ok(remaining) = unlock_time.duration_since(now) {
+            eprintln!(
+                "Day {day} is not unlocked yet, it unlocks in {}.",
+                format_remaining(remaining)
+            );
+            if puzzles.len() == 1 {
+                process::exit(1);
+            }
+            continue;
+        }
+
+        match &backend {
+            Backend::Native(client) => {
+                if let Err(e) = download(client, puzzle, overwrite) {
+                    eprintln!("failed to download day {day}: {e}");
+                    process::exit(1);
+                }
+            }
+            Backend::AocCli => {
+                let input_path = puzzle.input_path();
+                if !overwrite && has_content(&input_path) {
+                    println!("🎄 Input \"{input_path}\" is already present, skipping day {day}.");
+                    continue;
+                }
+
+                if let Err(e) = aoc_cli::download(puzzle) {
+                    eprintln!("failed to call aoc-cli: {e}");
+                    process::exit(1);
+                }
+
+                match fs::read_to_string(&input_path) {
+                    Ok(input) => {
+                        for problem in integrity::inspect(&input) {
+                            eprintln!("⚠️  Day {day}: {problem}");
+                        }
+                        if let Err(e) = Checksums::record(puzzle, &input) {
+                            eprintln!("failed to record checksum: {e}");
+                        }
+                    }
+                    Err(e) => eprintln!("failed to read \"{input_path}\": {e}"),
+                }
+
+                // aoc-cli writes plain files, encrypt them afterwards.
+                if encryption::is_enabled() {
+                    for path in [input_path, puzzle.puzzle_path()] {
+                        let result =
+                            fs::read(&path).and_then(|data| encryption::write(&path, data));
+                        if let Err(e) = result {
+                            eprintln!("failed to encrypt \"{path}\": {e}");
+                            process::exit(1);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn download(
+    client: &AocClient,
+    puzzle: Puzzle,
+    overwrite: bool,
+) -> Result<(), AocClientError> {
+    let input_path = puzzle.input_path();
+    let puzzle_path = puzzle.puzzle_path();
+
+    if !overwrite && has_content(&input_path) {
+        println!("🎄 Input \"{input_path}\" is already present, skipping.");
+    } else {
+        let input = client.input(puzzle)?;
+        if let Some(problem) = integrity::inspect(&input)
+            .into_iter()
+            .find(Problem::is_fatal)
+        {
+            return Err(AocClientError::InvalidInput(problem));
+        }
+        encryption::write(&input_path, &input)?;
+        Checksums::record(puzzle, &input)?;
+        println!("🎄 Successfully wrote input to \"{}\".", input_path);
     }
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
+    let description = if overwrite {
+        client.refresh_puzzle(puzzle)?
+    } else {
+        client.puzzle(puzzle)?
     };
+
+    encryption::write(&puzzle_path, &description.markdown)?;
+
+    if description.from_cache {
+        let age = SystemTime::now()
+            .duration_since(description.fetched_at)
+            .unwrap_or_default();
+        println!(
+            "🎄 Successfully wrote puzzle to \"{}\" (cached {} ago).",
+            puzzle_path,
+            format_remaining(age)
+        );
+    } else {
+        println!("🎄 Successfully wrote puzzle to \"{}\".", puzzle_path);
+    }
+
+    Ok(())
+}
+
+fn format_remaining(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    match secs {
+        s if s >= 86400 => format!("{}d {}h", s / 86400, (s % 86400) / 3600),
+        s if s >= 3600 => format!("{}h {}m", s / 3600, (s % 3600) / 60),
+        s if s >= 60 => format!("{}m {}s", s / 60, s % 60),
+        s => format!("{s}s"),
+    }
 }
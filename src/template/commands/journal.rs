@@ -0,0 +1,15 @@
+use crate::template::journal::Journal;
+use crate::template::layout;
+
+/// Prints the personal solve times recorded in the journal.
+pub fn handle() {
+    let year = layout::active_year();
+
+    let journal = Journal::read_from_file(year);
+    if journal.data.is_empty() {
+        println!("No days recorded yet. Days are recorded when they are scaffolded or solved.");
+        return;
+    }
+
+    println!("{}", journal.render(year));
+}
@@ -0,0 +1,27 @@
+use std::{fs, process};
+
+use crate::template::aoc_client::AocClient;
+use crate::template::leaderboard::{Leaderboard, SortBy};
+
+/// Prints a private leaderboard, loaded from `file` if given or fetched from the website.
+pub fn handle(id: &str, file: Option<&str>, sort: SortBy) {
+    let json = match file {
+        Some(path) => {
+            fs::read_to_string(path).map_err(|e| format!("could not read \"{path}\": {e}"))
+        }
+        None => AocClient::from_env()
+            .and_then(|client| client.leaderboard(id))
+            .map_err(|e| e.to_string()),
+    };
+
+    let mut leaderboard = match json.and_then(Leaderboard::try_from) {
+        Ok(leaderboard) => leaderboard,
+        Err(e) => {
+            eprintln!("failed to load leaderboard {id}: {e}");
+            process::exit(1);
+        }
+    };
+
+    leaderboard.sort(sort);
+    print!("{}", leaderboard.render());
+}
@@ -0,0 +1,123 @@
+use std::{fs, io, path::Path, process};
+
+use crate::template::layout::{self, Layout, SOLUTIONS_DIR};
+use crate::template::{Puzzle, all_days_in, config, readme_benchmarks};
+
+/// Files and directories of the data directory that belong to a single year.
+const YEAR_DATA: [&str; 7] = [
+    "inputs",
+    "examples",
+    "puzzles",
+    "answers.json",
+    "checksums.json",
+    "journal.json",
+    "timings.json",
+];
+
+/// Moves the flat layout of the active year under its year, so that further years can be added.
+/// Solutions are renamed from `src/bin/NN.rs` to `src/bin/YYYY-NN.rs`.
+pub fn handle() {
+    if Layout::detect() == Layout::YearQualified {
+        eprintln!("The repository already uses the year-qualified layout.");
+        process::exit(1);
+    }
+
+    let year = layout::active_year();
+
+    if let Err(e) = migrate(year) {
+        eprintln!("Failed to migrate to the year-qualified layout: {e}");
+        process::exit(1);
+    }
+
+    println!("---");
+    println!("🎄 Moved the solutions and data of {year} to the year-qualified layout.");
+}
+
+fn migrate(year: u16) -> Result<(), io::Error> {
+    let from_dir = Layout::Flat.data_dir(year);
+    let to_dir = Layout::YearQualified.data_dir(year);
+    fs::create_dir_all(&to_dir)?;
+
+    for name in YEAR_DATA {
+        let from = from_dir.join(name);
+        if from.exists() {
+            let to = to_dir.join(name);
+            fs::rename(&from, &to)?;
+            println!("Moved \"{}\" to \"{}\"", from.display(), to.display());
+        }
+    }
+
+    for day in all_days_in(year) {
+        let puzzle = Puzzle::new(year, day);
+        let from = format!("{SOLUTIONS_DIR}/{}.rs", Layout::Flat.bin_name(puzzle));
+        let to = format!(
+            "{SOLUTIONS_DIR}/{}.rs",
+            Layout::YearQualified.bin_name(puzzle)
+        );
+
+        if !Path::new(&from).exists() {
+            continue;
+        }
+
+        let source = fs::read_to_string(&from)?;
+        fs::write(&to, qualify_tests(&source))?;
+        fs::remove_file(&from)?;
+        println!("Moved \"{from}\" to \"{to}\"");
+    }
+
+    let readme_config = &config::get().readme;
+    let markers = [
+        readme_config.benchmarks_marker.as_str(),
+        readme_config.stars_marker.as_str(),
+    ];
+    let readme = fs::read_to_string(&readme_config.path)?;
+    fs::write(&readme_config.path, qualify_readme(&readme, &markers, year))?;
+    println!("Updated the readme sections of {year}");
+
+    Ok(())
+}
+
+/// Makes the tests of a solution read the examples of its year instead of the active year.
+fn qualify_tests(source: &str) -> String {
+    source.replace("(\"examples\", DAY", "(\"examples\", PUZZLE")
+}
+
+/// Turns the benchmark and star sections into the sections of a year.
+fn qualify_readme(readme: &str, markers: &[&str], year: u16) -> String {
+    markers.iter().fold(readme.to_string(), |readme, marker| {
+        readme.replace(
+            marker,
+            &readme_benchmarks::section_marker(marker, Some(year)),
+        )
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{qualify_readme, qualify_tests};
+    use crate::template::{readme_benchmarks, readme_stars};
+
+    #[test]
+    fn qualifies_example_paths() {
+        let source = "part_two(&advent_of_code::template::read_file_part(\"examples\", DAY, 2));";
+        assert_eq!(
+            qualify_tests(source),
+            "part_two(&advent_of_code::template::read_file_part(\"examples\", PUZZLE, 2));"
+        );
+    }
+
+    #[test]
+    fn qualifies_readme_sections() {
+        let readme = "<!--- advent_readme_stars table --->\n<!--- benchmarking table --->\n## Benchmarks\n<!--- benchmarking table --->";
+        assert_eq!(
+            qualify_readme(
+                readme,
+                &[readme_benchmarks::MARKER, readme_stars::MARKER],
+                2025
+            ),
+            "<!--- advent_readme_stars table 2025 --->\n<!--- benchmarking table 2025 --->\n## Benchmarks\n<!--- benchmarking table 2025 --->"
+        );
+    }
+}
@@ -1,6 +1,15 @@
 pub mod all;
+pub mod auth;
+pub mod crypt;
 pub mod download;
+pub mod journal;
+pub mod leaderboard;
+pub mod migrate;
 pub mod read;
+pub mod refresh;
 pub mod scaffold;
 pub mod solve;
 pub mod time;
+#[cfg(feature = "today")]
+pub mod today;
+pub mod verify;
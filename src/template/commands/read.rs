@@ -1,17 +1,64 @@
 use std::process;
 
-use crate::template::{Day, aoc_cli};
+use crate::template::aoc_client::{self, Backend};
+use crate::template::{Puzzle, aoc_cli, encryption, puzzle, render};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
-        process::exit(1);
-    }
+/// Prints the puzzle description of a day, optionally limited to one part.
+/// The stored description in `data/puzzles` is used if present, so this works offline.
+pub fn handle(puzzle: Puzzle, part: Option<u8>) {
+    let day = puzzle.day;
+    let puzzle_path = puzzle.puzzle_path();
+
+    let md = match encryption::read_to_string(&puzzle_path) {
+        Ok(md) if !md.trim().is_empty() => md,
+        _ => match fetch(puzzle) {
+            Some(md) => md,
+            // aoc-cli has already printed the puzzle.
+            None => return,
+        },
+    };
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
+    let section = match part {
+        Some(part) => match puzzle::part_section(&md, part) {
+            Some(section) => section,
+            None => {
+                eprintln!(
+                    "Part {part} is not available in \"{puzzle_path}\". Run `cargo refresh {day}` after solving part one."
+                );
+                process::exit(1);
+            }
+        },
+        None => &md,
     };
+
+    print!("{}", render::render(section, render::terminal_width()));
+}
+
+/// Downloads a puzzle that has not been stored yet.
+fn fetch(puzzle: Puzzle) -> Option<String> {
+    match aoc_client::backend() {
+        Ok(Backend::Native(client)) => match client.puzzle(puzzle) {
+            Ok(description) => {
+                if let Err(e) = encryption::write(puzzle.puzzle_path(), &description.markdown) {
+                    eprintln!("failed to store puzzle: {e}");
+                }
+                Some(description.markdown)
+            }
+            Err(e) => {
+                eprintln!("failed to fetch puzzle for day {}: {e}", puzzle.day);
+                process::exit(1);
+            }
+        },
+        Ok(Backend::AocCli) => {
+            if let Err(e) = aoc_cli::read(puzzle) {
+                eprintln!("failed to call aoc-cli: {e}");
+                process::exit(1);
+            }
+            None
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
 }
@@ -0,0 +1,68 @@
+use std::process;
+
+use crate::template::aoc_client::{AocClient, AocClientError};
+use crate::template::commands::scaffold;
+use crate::template::diff::{Change, diff_lines};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Puzzle, encryption, puzzle};
+
+pub fn handle(puzzle: Puzzle) {
+    let client = match AocClient::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    if let Err(e) = refresh(&client, puzzle) {
+        eprintln!("failed to refresh puzzle for day {}: {e}", puzzle.day);
+        process::exit(1);
+    }
+}
+
+/// Re-fetches the puzzle description without touching the input.
+/// The part one version is kept in a separate file and the changes to part two are printed.
+/// A new part two example and its expected answer are added to the day's files.
+pub fn refresh(client: &AocClient, puzzle: Puzzle) -> Result<(), AocClientError> {
+    let puzzle_path = puzzle.puzzle_path();
+    let old = encryption::read_to_string(&puzzle_path).unwrap_or_default();
+    let new = client.refresh_puzzle(puzzle)?.markdown;
+
+    if !old.trim().is_empty() && puzzle::part_section(&old, 2).is_none() {
+        let snapshot_path = puzzle::get_part_one_snapshot_path(puzzle);
+        encryption::write(&snapshot_path, &old)?;
+        println!("🎄 Kept part one description in \"{snapshot_path}\".");
+    }
+
+    encryption::write(&puzzle_path, &new)?;
+    println!("🎄 Successfully wrote puzzle to \"{puzzle_path}\".");
+
+    let Some(new_part_two) = puzzle::part_section(&new, 2) else {
+        println!("Part two is not available yet.");
+        return Ok(());
+    };
+
+    scaffold::extract_part_two(puzzle);
+
+    let old_part_two = puzzle::part_section(&old, 2).unwrap_or_default();
+    let changes = diff_lines(old_part_two, new_part_two);
+
+    if changes
+        .iter()
+        .all(|change| matches!(change, Change::Same(_)))
+    {
+        println!("Part two did not change.");
+        return Ok(());
+    }
+
+    println!("---");
+    for change in changes {
+        match change {
+            Change::Added(line) => println!("{ANSI_BOLD}+ {line}{ANSI_RESET}"),
+            Change::Removed(line) => println!("- {line}"),
+            Change::Same(_) => {}
+        }
+    }
+
+    Ok(())
+}
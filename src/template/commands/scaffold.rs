@@ -1,13 +1,13 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
     process,
+    time::SystemTime,
 };
 
-use crate::template::Day;
-
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+use crate::template::examples::{self, CodeBlock};
+use crate::template::journal::{Event, Journal};
+use crate::template::{Puzzle, config, encryption, normalize, puzzle, shape, templates};
 
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
@@ -27,10 +27,49 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
         .open(path)
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Creates the directories the data of a puzzle is stored in.
+pub fn create_data_dirs(puzzle: Puzzle) {
+    for dir in ["inputs", "examples", "puzzles"] {
+        if let Err(e) = fs::create_dir_all(puzzle.data_dir().join(dir)) {
+            eprintln!("Failed to create data directory: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Creates the module of a puzzle from a template, the one configured in `aoc.toml` by default.
+/// An input that is already present is kept.
+pub fn handle(puzzle: Puzzle, template: Option<&str>, overwrite: bool, pick: bool) {
+    let day = puzzle.day;
+    let input_path = puzzle.input_path();
+    let example_path = puzzle.example_path(1);
+    let module_path = puzzle.module_path();
+
+    create_data_dirs(puzzle);
+
+    let scaffold_config = &config::get().scaffold;
+    let template_name = template.unwrap_or(&scaffold_config.template);
+    let template = match templates::read(template_name) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("Failed to read template: {e}");
+            process::exit(1);
+        }
+    };
+
+    let description = encryption::read_to_string(puzzle.puzzle_path()).ok();
+    // without an input, the parser reads the lines of the input.
+    let input = encryption::read_to_string(&input_path)
+        .map(|input| normalize(&input))
+        .unwrap_or_default();
+    let parser = shape::parser(&shape::detect(&input));
+    let module = templates::render(
+        &template,
+        puzzle,
+        description.as_deref().and_then(puzzle::title),
+        Some(&parser),
+        &scaffold_config.placeholders,
+    );
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -40,13 +79,12 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    match file.write_all(module.as_bytes()) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
+            if let Err(e) = Journal::record(puzzle, Event::Scaffolded, SystemTime::now()) {
+                eprintln!("Failed to record scaffold time: {e}");
+            }
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {e}");
@@ -54,26 +92,175 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+    if encryption::has_content(&input_path) {
+        println!("Kept existing input file \"{}\"", &input_path);
+    } else {
+        match create_file(&input_path) {
+            Ok(_) => {
+                println!("Created empty input file \"{}\"", &input_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to create input file: {e}");
+                process::exit(1);
+            }
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+    if !extract_examples(puzzle, pick) {
+        match create_file(&example_path) {
+            Ok(_) => {
+                println!("Created empty example file \"{}\"", &example_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to create example file: {e}");
+                process::exit(1);
+            }
         }
     }
 
     println!("---");
     println!("🎄 Type `cargo solve {day}` to run your solution.");
 }
+
+/// Writes the example inputs found in the stored puzzle description to the examples directory.
+/// The first example is written to `NN.txt`, further ones to `NN-2.txt` and so on.
+/// With `pick`, the examples are chosen interactively instead of by heuristic.
+///
+/// The generated tests are filled with the example answers found in the description.
+///
+/// Returns `false` if the puzzle has not been downloaded yet or no example was found.
+pub fn extract_examples(puzzle: Puzzle, pick: bool) -> bool {
+    let Ok(md) = encryption::read_to_string(puzzle.puzzle_path()) else {
+        return false;
+    };
+    let input = encryption::read_to_string(puzzle.input_path()).ok();
+
+    let blocks = examples::code_blocks(&md);
+    let mut picked = examples::pick(&blocks, input.as_deref());
+
+    if pick && !blocks.is_empty() {
+        picked = prompt_pick(&blocks, &picked);
+    }
+
+    for (n, i) in picked.iter().enumerate() {
+        let example_path = puzzle.example_path(n + 1);
+        match encryption::write(&example_path, &blocks[*i].content) {
+            Ok(()) => {
+                println!("Created example file \"{example_path}\" from the puzzle description");
+            }
+            Err(e) => {
+                eprintln!("Failed to write example file: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    fill_tests(puzzle, &md, &blocks, &picked, &[1, 2]);
+
+    !picked.is_empty()
+}
+
+/// Updates the examples and the generated part two test after part two was revealed.
+/// A new part two example is only written if there is no `NN-2.txt` yet.
+pub fn extract_part_two(puzzle: Puzzle) {
+    let Ok(md) = encryption::read_to_string(puzzle.puzzle_path()) else {
+        return;
+    };
+    let input = encryption::read_to_string(puzzle.input_path()).ok();
+
+    let blocks = examples::code_blocks(&md);
+    let picked = examples::pick(&blocks, input.as_deref());
+    let n = examples::number_for_part(&blocks, &picked, 2);
+
+    let example_path = puzzle.example_path(n);
+    if n > 1 && !encryption::has_content(&example_path) {
+        match encryption::write(&example_path, &blocks[picked[n - 1]].content) {
+            Ok(()) => {
+                println!("Created example file \"{example_path}\" from the puzzle description");
+            }
+            Err(e) => {
+                eprintln!("Failed to write example file: {e}");
+                return;
+            }
+        }
+    }
+
+    fill_tests(puzzle, &md, &blocks, &picked, &[2]);
+}
+
+/// Makes the generated tests of the given parts assert the example answers.
+fn fill_tests(puzzle: Puzzle, md: &str, blocks: &[CodeBlock], picked: &[usize], parts: &[u8]) {
+    let module_path = puzzle.module_path();
+    let Ok(mut source) = fs::read_to_string(&module_path) else {
+        return;
+    };
+
+    let mut filled = vec![];
+    for &part in parts {
+        let Some(answer) = puzzle::example_answer(md, part) else {
+            continue;
+        };
+        let n = examples::number_for_part(blocks, picked, part);
+        if let Some(updated) = examples::fill_test(&source, part, n, &answer) {
+            source = updated;
+            filled.push(part.to_string());
+        }
+    }
+
+    if filled.is_empty() {
+        return;
+    }
+
+    match fs::write(&module_path, source) {
+        Ok(()) => println!(
+            "Filled in the expected example answers of part {} in \"{module_path}\"",
+            filled.join(" and ")
+        ),
+        Err(e) => eprintln!("Failed to update module file: {e}"),
+    }
+}
+
+/// Lists all code blocks and asks which ones to use as examples, in order.
+fn prompt_pick(blocks: &[CodeBlock], suggested: &[usize]) -> Vec<usize> {
+    for (i, block) in blocks.iter().enumerate() {
+        let marker = if suggested.contains(&i) { "*" } else { " " };
+        println!("{marker}[{}] part {}: {}", i + 1, block.part, block.context);
+
+        let lines: Vec<&str> = block.content.lines().collect();
+        for line in lines.iter().take(3) {
+            println!("      {line}");
+        }
+        if lines.len() > 3 {
+            println!("      ... ({} lines)", lines.len());
+        }
+    }
+
+    let default: Vec<String> = suggested.iter().map(|i| (i + 1).to_string()).collect();
+    print!(
+        "Pick example blocks in order, separated by commas [{}]: ",
+        default.join(",")
+    );
+    let _ = io::stdout().flush();
+
+    let mut answer = String::new();
+    if io::stdin().read_line(&mut answer).is_err() || answer.trim().is_empty() {
+        return suggested.to_vec();
+    }
+
+    let picked: Option<Vec<usize>> = answer
+        .trim()
+        .split(',')
+        .map(|n| {
+            n.trim()
+                .parse::<usize>()
+                .ok()
+                .filter(|n| (1..=blocks.len()).contains(n))
+                .map(|n| n - 1)
+        })
+        .collect();
+
+    picked.unwrap_or_else(|| {
+        eprintln!("Invalid selection, using the suggested examples.");
+        suggested.to_vec()
+    })
+}
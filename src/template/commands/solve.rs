@@ -1,9 +1,17 @@
-use std::process::{Command, Stdio};
+use std::process::{self, Command, Stdio};
 
-use crate::template::Day;
+use crate::template::answers::Answers;
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, Puzzle, inputs};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// Runs the solution of a puzzle, on its n-th example if `example` is given.
+pub fn handle(
+    puzzle: Puzzle,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    example: Option<usize>,
+) {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), puzzle.bin_name()];
 
     if dhat {
         cmd_args.extend([
@@ -23,6 +31,11 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
+    if let Some(example) = example {
+        cmd_args.push("--example".to_string());
+        cmd_args.push(example.to_string());
+    }
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
@@ -32,3 +45,23 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
 
     cmd.wait().unwrap();
 }
+
+/// Runs the solution over every input of the day and reports mismatches with the expected answers.
+pub fn handle_all_inputs(day: Day, release: bool) {
+    let inputs = inputs::list(day, &Answers::read_all());
+
+    if inputs.is_empty() {
+        eprintln!("No inputs found for day {day}.");
+        process::exit(1);
+    }
+
+    let mismatches = inputs::check_all(day, &inputs, release);
+
+    if !mismatches.is_empty() {
+        eprintln!("\n{ANSI_BOLD}Mismatches:{ANSI_RESET}");
+        for mismatch in mismatches {
+            eprintln!("  ✖ {mismatch}");
+        }
+        process::exit(1);
+    }
+}
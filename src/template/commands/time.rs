@@ -1,8 +1,9 @@
 use std::collections::HashSet;
 
+use crate::template::answers::Answers;
 use crate::template::run_multi::run_multi;
 use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::{Day, all_days, config, readme_benchmarks, readme_stars};
 
 pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
     let stored_timings = Timings::read_from_file();
@@ -28,13 +29,21 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         merged_timings.store_file().unwrap();
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
-            Ok(()) => {
-                println!("Stored updated benchmarks.");
-            }
-            Err(_) => {
-                eprintln!("Failed to store updated benchmarks.");
+        if config::get().benchmarks.update_readme {
+            match readme_benchmarks::update(merged_timings) {
+                Ok(()) => {
+                    println!("Stored updated benchmarks.");
+                }
+                Err(_) => {
+                    eprintln!("Failed to store updated benchmarks.");
+                }
             }
+        } else {
+            println!("Stored updated benchmarks.");
+        }
+
+        if readme_stars::update(&Answers::read_all()).is_err() {
+            eprintln!("Failed to update star progress.");
         }
     }
 }
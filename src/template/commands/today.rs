@@ -0,0 +1,150 @@
+use std::{
+    io::{Write, stdout},
+    path::Path,
+    process,
+    time::Duration,
+};
+
+use crate::template::aoc_client::{self, Backend};
+use crate::template::clock::{self, Clock, SystemClock};
+use crate::template::commands::{download, read, scaffold};
+use crate::template::leaderboard::format_duration;
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, Puzzle, layout};
+
+/// How often to retry downloading an input that is not available right after unlock.
+const RETRY_ATTEMPTS: usize = 10;
+const RETRY_DELAY: Duration = Duration::from_secs(3);
+
+/// Scaffolds, downloads and reads the current day.
+/// With `wait`, the next unlock is waited for first if today's puzzle is not available or already scaffolded.
+pub fn handle(wait: bool) {
+    let day = if wait {
+        let year = layout::active_year();
+
+        let Some(day) = wait_for_unlock(&SystemClock, year, print_countdown) else {
+            eprintln!("All puzzles of {year} are unlocked already.");
+            process::exit(1);
+        };
+
+        day
+    } else {
+        match Day::today() {
+            Some(day) => day,
+            None => {
+                eprintln!(
+                    "`today` command can only be run during advent, i.e. between the 1st and \
+                    the 12th of december (the 25th before 2025). Please use `scaffold` with a \
+                    specific day or wait for the next unlock with `--wait`."
+                );
+                process::exit(1)
+            }
+        }
+    };
+
+    let puzzle = Puzzle::current(day);
+    scaffold::create_data_dirs(puzzle);
+    fetch(&SystemClock, puzzle);
+    scaffold::handle(puzzle, None, false, false);
+    read::handle(puzzle, None);
+}
+
+/// Returns today's puzzle of `year` if it is not scaffolded yet, or else waits for the next puzzle
+/// to unlock and returns its day. `countdown` is called with the remaining time about once per
+/// second. Returns `None` if all puzzles of the year are unlocked already.
+pub fn wait_for_unlock(
+    clock: &impl Clock,
+    year: u16,
+    mut countdown: impl FnMut(Day, Duration),
+) -> Option<Day> {
+    let now = clock.now();
+    let day = match Day::unlocked_on(year, now) {
+        Some(day) if !Path::new(&Puzzle::new(year, day).module_path()).exists() => {
+            return Some(day);
+        }
+        _ => Day::next_unlock(year, now)?,
+    };
+
+    clock::wait_until(clock, day.unlock_time(year), |remaining| {
+        countdown(day, remaining);
+    });
+    println!("\r🎄 Day {day} is unlocked!                ");
+
+    Some(day)
+}
+
+fn print_countdown(day: Day, remaining: Duration) {
+    print!(
+        "\r⏳ Day {day} unlocks in {ANSI_BOLD}{}{ANSI_RESET} ",
+        format_duration(Some(
+            remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0)
+        ))
+    );
+    let _ = stdout().flush();
+}
+
+/// Downloads the input, retrying briefly because it can take a moment to become available after unlock.
+fn fetch(clock: &impl Clock, puzzle: Puzzle) {
+    match aoc_client::backend() {
+        Ok(Backend::Native(client)) => {
+            let result = clock::retry(clock, RETRY_ATTEMPTS, RETRY_DELAY, || {
+                download::download(&client, puzzle, false).inspect_err(|e| {
+                    eprintln!("failed to download day {}: {e}", puzzle.day);
+                })
+            });
+            if result.is_err() {
+                process::exit(1);
+            }
+        }
+        _ => download::handle(&[puzzle], false),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::wait_for_unlock;
+    use crate::day;
+    use crate::template::clock::{Clock, FakeClock};
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn counts_down_to_next_unlock() {
+        let unlock = day!(1).unlock_time(2025);
+        let clock = FakeClock::new(unlock - Duration::from_millis(2500));
+        let mut ticks = vec![];
+
+        let day = wait_for_unlock(&clock, 2025, |day, remaining| ticks.push((day, remaining)));
+
+        assert_eq!(day, Some(day!(1)));
+        assert_eq!(clock.now(), unlock);
+        assert_eq!(
+            ticks,
+            vec![
+                (day!(1), Duration::from_millis(2500)),
+                (day!(1), Duration::from_millis(1500)),
+                (day!(1), Duration::from_millis(500))
+            ]
+        );
+    }
+
+    #[test]
+    fn returns_todays_puzzle_if_not_scaffolded() {
+        let now = day!(20).unlock_time(2015) + Duration::from_secs(3600);
+        let clock = FakeClock::new(now);
+
+        let day = wait_for_unlock(&clock, 2015, |_, _| panic!("should not wait"));
+
+        assert_eq!(day, Some(day!(20)));
+        assert_eq!(clock.now(), now);
+    }
+
+    #[test]
+    fn does_not_wait_after_last_unlock() {
+        let clock = FakeClock::new(UNIX_EPOCH + Duration::from_secs(1_451_606_400));
+        assert_eq!(
+            wait_for_unlock(&clock, 2015, |_, _| panic!("should not wait")),
+            None
+        );
+    }
+}
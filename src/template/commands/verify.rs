@@ -0,0 +1,51 @@
+use std::{collections::HashSet, process};
+
+use crate::template::answers::Answers;
+use crate::template::inputs;
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, all_days, readme_stars};
+
+/// Runs the solutions of all days with expected answers over each of their inputs and compares
+/// the results. Afterwards, the star progress in the readme is updated.
+pub fn handle(day: Option<Day>) {
+    let answers = Answers::read_all();
+    let days: HashSet<Day> = day.map_or_else(|| all_days().collect(), |day| HashSet::from([day]));
+
+    let mut mismatches = vec![];
+    let mut need_space = false;
+
+    for day in all_days().filter(|day| days.contains(day)) {
+        let inputs: Vec<_> = inputs::list(day, &answers)
+            .into_iter()
+            .filter(|input| input.expected.iter().any(Option::is_some))
+            .collect();
+
+        if inputs.is_empty() {
+            continue;
+        }
+
+        if need_space {
+            println!();
+        }
+        need_space = true;
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+
+        mismatches.extend(inputs::check_all(day, &inputs, true));
+    }
+
+    match readme_stars::update(&answers) {
+        Ok(()) => println!("\nUpdated star progress ({} ⭐).", answers.stars()),
+        Err(_) => eprintln!("\nFailed to update star progress."),
+    }
+
+    if !mismatches.is_empty() {
+        eprintln!("\n{ANSI_BOLD}Mismatches:{ANSI_RESET}");
+        for mismatch in mismatches {
+            eprintln!("  ✖ {mismatch}");
+        }
+        process::exit(1);
+    }
+
+    println!("All expected answers verified.");
+}
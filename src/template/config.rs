@@ -0,0 +1,217 @@
+//! Project settings, read from `aoc.toml` in the project root.
+//!
+//! Every setting is optional, a missing file or key falls back to the defaults below. The year can
+//! be overridden for a single command with `AOC_YEAR`, e.g. `AOC_YEAR=2024 cargo scaffold 3`.
+use std::{
+    collections::BTreeMap,
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    process,
+    sync::OnceLock,
+    time::Duration,
+};
+
+use serde::Deserialize;
+
+use crate::template::layout::{self, FIRST_YEAR};
+use crate::template::{readme_benchmarks, readme_stars, templates};
+
+const CONFIG_FILE: &str = "aoc.toml";
+const YEAR_ENV_VAR: &str = "AOC_YEAR";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// The year commands work on. Defaults to the latest year with puzzles.
+    pub year: Option<u16>,
+    pub paths: Paths,
+    pub benchmarks: Benchmarks,
+    pub readme: Readme,
+    pub scaffold: Scaffold,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Paths {
+    /// Directory of inputs, examples, puzzle descriptions and the JSON stores.
+    pub data: PathBuf,
+}
+
+impl Default for Paths {
+    fn default() -> Self {
+        Self {
+            data: PathBuf::from("data"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Benchmarks {
+    /// Time a part is benchmarked for, in milliseconds.
+    pub target_millis: u64,
+    pub min_samples: u128,
+    pub max_samples: u128,
+    /// Whether `cargo time --store` updates the readme tables.
+    pub update_readme: bool,
+}
+
+impl Benchmarks {
+    #[must_use]
+    pub fn target(&self) -> Duration {
+        Duration::from_millis(self.target_millis)
+    }
+}
+
+impl Default for Benchmarks {
+    fn default() -> Self {
+        Self {
+            target_millis: 1000,
+            min_samples: 10,
+            max_samples: 10_000,
+            update_readme: true,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Readme {
+    pub path: PathBuf,
+    pub benchmarks_marker: String,
+    pub stars_marker: String,
+}
+
+impl Default for Readme {
+    fn default() -> Self {
+        Self {
+            path: PathBuf::from("README.md"),
+            benchmarks_marker: readme_benchmarks::MARKER.into(),
+            stars_marker: readme_stars::MARKER.into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Scaffold {
+    /// Template of new solutions: a built-in template, or the name of a file in `templates/`.
+    pub template: String,
+    /// Values of custom placeholders, e.g. `AUTHOR = "me"` fills in `%AUTHOR%`.
+    pub placeholders: BTreeMap<String, String>,
+}
+
+impl Default for Scaffold {
+    fn default() -> Self {
+        Self {
+            template: templates::DEFAULT_TEMPLATE.into(),
+            placeholders: BTreeMap::new(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    IO(io::Error),
+    Parse(String),
+    InvalidYear(String),
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::IO(e) => write!(f, "{e}"),
+            ConfigError::Parse(e) => write!(f, "{e}"),
+            ConfigError::InvalidYear(year) => {
+                write!(f, "\"{year}\" is not a year of advent of code.")
+            }
+        }
+    }
+}
+
+impl From<io::Error> for ConfigError {
+    fn from(e: io::Error) -> Self {
+        ConfigError::IO(e)
+    }
+}
+
+impl Config {
+    /// Reads the config file, applying the `AOC_YEAR` override. A missing file yields the defaults.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let mut config = match fs::read_to_string(path) {
+            Ok(s) => Self::parse(&s)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Self::default(),
+            Err(e) => return Err(e.into()),
+        };
+
+        if let Ok(year) = env::var(YEAR_ENV_VAR) {
+            config.year =
+                Some(layout::parse_year(year.trim()).ok_or(ConfigError::InvalidYear(year))?);
+        }
+
+        Ok(config)
+    }
+
+    pub fn parse(s: &str) -> Result<Self, ConfigError> {
+        let config: Self = toml::from_str(s).map_err(|e| ConfigError::Parse(e.to_string()))?;
+
+        match config.year {
+            Some(year) if year < FIRST_YEAR => Err(ConfigError::InvalidYear(year.to_string())),
+            _ => Ok(config),
+        }
+    }
+}
+
+/// Returns the settings of this project, read once per process.
+/// Exits if the config file is invalid, as no command can work with it.
+pub fn get() -> &'static Config {
+    static CONFIG: OnceLock<Config> = OnceLock::new();
+
+    CONFIG.get_or_init(|| {
+        Config::load(CONFIG_FILE).unwrap_or_else(|e| {
+            eprintln!("Failed to load the project config \"{CONFIG_FILE}\": {e}");
+            process::exit(1);
+        })
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Config, ConfigError};
+    use std::path::Path;
+
+    #[test]
+    fn defaults_missing_settings() {
+        let config = Config::parse("year = 2024\n[readme]\npath = \"docs/README.md\"").unwrap();
+        assert_eq!(config.year, Some(2024));
+        assert_eq!(config.readme.path, Path::new("docs/README.md"));
+        assert_eq!(
+            config.readme.stars_marker,
+            "<!--- advent_readme_stars table --->"
+        );
+        assert_eq!(config.paths.data, Path::new("data"));
+        assert_eq!(config.benchmarks.max_samples, 10_000);
+        assert_eq!(config.scaffold.template, "default");
+    }
+
+    #[test]
+    fn parses_empty_file() {
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn rejects_invalid_settings() {
+        assert!(matches!(
+            Config::parse("year = 1999"),
+            Err(ConfigError::InvalidYear(_))
+        ));
+        assert!(matches!(
+            Config::parse("[benchmarks]\nsamples = 10"),
+            Err(ConfigError::Parse(_))
+        ));
+    }
+}
@@ -1,14 +1,30 @@
 use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 #[cfg(feature = "today")]
 use chrono::{Datelike, FixedOffset, Utc};
 
-#[cfg(feature = "today")]
+use crate::template::layout;
+
 const SERVER_UTC_OFFSET: i32 = -5;
 
+/// Since 2025, there are only 12 days of puzzles.
+const FIRST_SHORT_YEAR: u16 = 2025;
+
+/// Returns the number of days of advent in the given year: 25 until 2024 and 12 from 2025 on.
+pub const fn days_in_year(year: u16) -> u8 {
+    if year >= FIRST_SHORT_YEAR { 12 } else { 25 }
+}
+
+/// Returns the number of days of advent in the [active year](crate::template::layout::active_year).
+pub fn days_in_active_year() -> u8 {
+    days_in_year(layout::active_year())
+}
+
 /// A valid day number of advent (i.e. an integer in range 1 to 25).
+/// Years from 2025 on only have 12 days, see [`days_in_year`].
 ///
 /// # Display
 /// This value displays as a two digit number.
@@ -31,19 +47,64 @@ impl Day {
         Some(Self(day))
     }
 
+    /// Parses a day of any year, i.e. a number from 1 to 25.
+    /// Stored data is parsed with this, as it can hold days of another year than the configured one.
+    pub fn parse_any(s: &str) -> Option<Self> {
+        s.parse().ok().and_then(Self::new)
+    }
+
     /// Converts the [`Day`] into an [`u8`].
     pub fn into_inner(self) -> u8 {
         self.0
     }
+
+    /// Returns the moment the puzzle for this day unlocks in the given year, i.e. midnight at the server's UTC-5.
+    pub fn unlock_time(self, year: u16) -> SystemTime {
+        let days = days_from_civil(i64::from(year), 12, i64::from(self.0));
+        let secs = days * 86400 - i64::from(SERVER_UTC_OFFSET) * 3600;
+        // NOTE: advent of code started in 2015, so the timestamp is always positive.
+        UNIX_EPOCH + Duration::from_secs(secs.unsigned_abs())
+    }
+
+    /// Returns `true` if the puzzle for this day is available at the given moment.
+    pub fn is_unlocked(self, year: u16, now: SystemTime) -> bool {
+        now >= self.unlock_time(year)
+    }
+
+    /// Returns the day whose puzzle unlocked at the server's last midnight, if `now` is on one of the days of advent.
+    pub fn unlocked_on(year: u16, now: SystemTime) -> Option<Self> {
+        all_days_in(year).find(|day| {
+            now.duration_since(day.unlock_time(year))
+                .is_ok_and(|elapsed| elapsed < Duration::from_secs(86400))
+        })
+    }
+
+    /// Returns the next day to unlock after `now`, if any is left in the given year.
+    pub fn next_unlock(year: u16, now: SystemTime) -> Option<Self> {
+        all_days_in(year).find(|day| !day.is_unlocked(year, now))
+    }
+}
+
+/// Returns the number of days since the unix epoch for a date of the proleptic gregorian calendar.
+/// See: https://howardhinnant.github.io/date_algorithms.html#days_from_civil
+const fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
 }
 
 #[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
+    /// Returns the current day if it's one of the days of advent, `None` otherwise.
+    /// The number of days is the one of the [active year](crate::template::layout::active_year), like for parsing.
     pub fn today() -> Option<Self> {
         let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
         let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
+        let last = days_in_year(crate::template::layout::active_year());
+        if today.month() == 12 && today.day() <= u32::from(last) {
             Self::new(u8::try_from(today.day()).ok()?)
         } else {
             None
@@ -74,40 +135,57 @@ impl PartialOrd<u8> for Day {
 impl FromStr for Day {
     type Err = DayFromStrError;
 
+    /// Parses a day of the configured year.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let day = s.parse().map_err(|_| DayFromStrError)?;
-        Self::new(day).ok_or(DayFromStrError)
+        let last = days_in_active_year();
+        let error = DayFromStrError { last };
+        let day = s.parse().map_err(|_| error)?;
+        Self::new(day).filter(|day| day.0 <= last).ok_or(error)
     }
 }
 
 /// An error which can be returned when parsing a [`Day`].
-#[derive(Debug)]
-pub struct DayFromStrError;
+#[derive(Debug, Clone, Copy)]
+pub struct DayFromStrError {
+    last: u8,
+}
 
 impl Error for DayFromStrError {}
 
 impl Display for DayFromStrError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("expecting a day number between 1 and 25")
+        write!(f, "expecting a day number between 1 and {}", self.last)
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of advent of the configured year.
 pub fn all_days() -> AllDays {
     AllDays::new()
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of advent of the given year.
+pub fn all_days_in(year: u16) -> AllDays {
+    AllDays {
+        current: 1,
+        last: days_in_year(year),
+    }
+}
+
+/// An iterator that yields every day of advent from the 1st to the last day of a year.
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        Self { current: 1 }
+        Self {
+            current: 1,
+            last: days_in_active_year(),
+        }
     }
 }
 
@@ -115,10 +193,10 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
+        // NOTE: the iterator starts at 1 and the last day of a year is never above 25.
         let day = Day(self.current);
         self.current += 1;
 
@@ -143,11 +221,69 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{Day, all_days};
+    use super::{Day, all_days_in, days_in_year};
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn unlock_time() {
+        assert_eq!(
+            Day(1).unlock_time(2025),
+            UNIX_EPOCH + Duration::from_secs(1_764_565_200)
+        );
+        assert_eq!(
+            Day(25).unlock_time(2015),
+            UNIX_EPOCH + Duration::from_secs(1_451_019_600)
+        );
+    }
+
+    #[test]
+    fn parses_days_of_any_year() {
+        assert_eq!(Day::parse_any("20"), Some(Day(20)));
+        assert_eq!(Day::parse_any("0"), None);
+        assert_eq!(Day::parse_any("26"), None);
+    }
+
+    #[test]
+    fn is_unlocked() {
+        let unlock = Day(3).unlock_time(2025);
+        assert!(!Day(3).is_unlocked(2025, unlock - Duration::from_secs(1)));
+        assert!(Day(3).is_unlocked(2025, unlock));
+    }
+
+    #[test]
+    fn finds_current_and_next_unlock() {
+        let unlock = Day(3).unlock_time(2025);
+        let before = unlock - Duration::from_secs(10);
+
+        assert_eq!(Day::unlocked_on(2025, before), Some(Day(2)));
+        assert_eq!(Day::next_unlock(2025, before), Some(Day(3)));
+        assert_eq!(Day::unlocked_on(2025, unlock), Some(Day(3)));
+        assert_eq!(Day::next_unlock(2025, unlock), Some(Day(4)));
+
+        let november = Day(1).unlock_time(2025) - Duration::from_secs(86400);
+        assert_eq!(Day::unlocked_on(2025, november), None);
+        assert_eq!(Day::next_unlock(2025, november), Some(Day(1)));
+        assert_eq!(Day::next_unlock(2024, november), None);
+
+        let last = Day(12).unlock_time(2025);
+        assert_eq!(
+            Day::unlocked_on(2025, last + Duration::from_secs(86400)),
+            None
+        );
+        assert_eq!(Day::next_unlock(2025, last), None);
+    }
+
+    #[test]
+    fn days_per_year() {
+        assert_eq!(days_in_year(2015), 25);
+        assert_eq!(days_in_year(2024), 25);
+        assert_eq!(days_in_year(2025), 12);
+        assert_eq!(all_days_in(2025).last(), Some(Day(12)));
+    }
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
+        let mut iter = all_days_in(2024);
 
         assert_eq!(iter.next(), Some(Day(1)));
         assert_eq!(iter.next(), Some(Day(2)));
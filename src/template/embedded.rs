@@ -0,0 +1,45 @@
+//! Inputs embedded into the solution binaries at compile time, with the `embed-inputs` feature.
+//!
+//! `build.rs` includes the bytes of `data/inputs/NN.txt` into the binary of each day and fails
+//! compilation if the file is missing. Solutions then start without reading the input from disk,
+//! and `bytes` solutions also skip the UTF-8 validation. Inputs passed with `--input <path>` or
+//! `--example <n>` are still read from their files.
+use std::{borrow::Cow, str};
+
+use crate::template::{Puzzle, integrity, named_input_path, normalize};
+
+/// Returns the embedded input like [`read_input`](crate::template::read_input).
+#[must_use]
+pub fn read_input(puzzle: impl Into<Puzzle>, embedded: &'static [u8]) -> String {
+    normalize(&read_input_raw(puzzle, embedded))
+}
+
+/// Returns the embedded input like [`read_input_raw`](crate::template::read_input_raw).
+///
+/// # Panics
+/// If the embedded input is not valid UTF-8.
+#[must_use]
+pub fn read_input_raw(puzzle: impl Into<Puzzle>, embedded: &'static [u8]) -> String {
+    let puzzle = puzzle.into();
+
+    if named_input_path(puzzle).is_some() {
+        return super::read_input_raw(puzzle);
+    }
+
+    let input = str::from_utf8(embedded).expect("embedded input is not valid UTF-8");
+    integrity::check_input(puzzle, input, true);
+    input.to_string()
+}
+
+/// Returns the embedded input like [`read_input_bytes`](crate::template::read_input_bytes),
+/// without copying or checking it.
+#[must_use]
+pub fn read_input_bytes(puzzle: impl Into<Puzzle>, embedded: &'static [u8]) -> Cow<'static, [u8]> {
+    let puzzle = puzzle.into();
+
+    if named_input_path(puzzle).is_some() {
+        super::read_input_bytes(puzzle)
+    } else {
+        Cow::Borrowed(embedded)
+    }
+}
@@ -0,0 +1,242 @@
+//! Encryption of inputs and puzzle descriptions at rest, so they can live in a public repository.
+//!
+//! Encrypted files are stored next to where the plain file would be, with an `.enc` suffix
+//! (e.g. `data/inputs/01.txt.enc`). They are keyed by a local passphrase file that must never be
+//! committed: `.aoc_passphrase` in the project root, or the file set in `AOC_PASSPHRASE_FILE`.
+//!
+//! File format: `AOC1` magic, 16 byte salt, 24 byte nonce, XChaCha20-Poly1305 ciphertext.
+//! The key is derived from the passphrase and salt with PBKDF2-HMAC-SHA256.
+use std::{
+    collections::HashMap,
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::{Mutex, OnceLock},
+};
+
+use chacha20poly1305::{
+    Key, XChaCha20Poly1305, XNonce,
+    aead::{Aead, AeadCore, KeyInit, OsRng, rand_core::RngCore},
+};
+
+pub const SUFFIX: &str = ".enc";
+const PASSPHRASE_FILE: &str = ".aoc_passphrase";
+const PASSPHRASE_ENV_VAR: &str = "AOC_PASSPHRASE_FILE";
+
+const MAGIC: &[u8; 4] = b"AOC1";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+const PBKDF2_ROUNDS: u32 = 100_000;
+
+#[derive(Debug)]
+pub enum EncryptionError {
+    NoPassphrase(PathBuf),
+    WrongPassphrase,
+    Malformed,
+    IO(io::Error),
+}
+
+impl Display for EncryptionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EncryptionError::NoPassphrase(path) => write!(
+                f,
+                "no passphrase found in \"{}\". Create it to encrypt or decrypt inputs.",
+                path.display()
+            ),
+            EncryptionError::WrongPassphrase => {
+                write!(f, "could not decrypt file, is the passphrase correct?")
+            }
+            EncryptionError::Malformed => write!(f, "file is not encrypted by this template."),
+            EncryptionError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for EncryptionError {
+    fn from(e: io::Error) -> Self {
+        EncryptionError::IO(e)
+    }
+}
+
+impl From<EncryptionError> for io::Error {
+    fn from(e: EncryptionError) -> Self {
+        match e {
+            EncryptionError::IO(e) => e,
+            e => io::Error::new(io::ErrorKind::InvalidData, e.to_string()),
+        }
+    }
+}
+
+/// Returns the path of the local passphrase file.
+#[must_use]
+pub fn passphrase_path() -> PathBuf {
+    env::var_os(PASSPHRASE_ENV_VAR).map_or_else(|| PathBuf::from(PASSPHRASE_FILE), PathBuf::from)
+}
+
+/// Reads the passphrase, ignoring surrounding whitespace.
+pub fn read_passphrase() -> Result<String, EncryptionError> {
+    let path = passphrase_path();
+    fs::read_to_string(&path)
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .ok_or(EncryptionError::NoPassphrase(path))
+}
+
+/// Files are written encrypted if a passphrase is configured.
+#[must_use]
+pub fn is_enabled() -> bool {
+    read_passphrase().is_ok()
+}
+
+/// Returns the path of the encrypted version of a file.
+#[must_use]
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(SUFFIX);
+    PathBuf::from(path)
+}
+
+#[must_use]
+pub fn encrypt(plaintext: &[u8], passphrase: &str) -> Vec<u8> {
+    // all files encrypted by one process share a salt, so the key is only derived once.
+    static SALT: OnceLock<[u8; SALT_LEN]> = OnceLock::new();
+    let salt = SALT.get_or_init(|| {
+        let mut salt = [0; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+        salt
+    });
+
+    let cipher = XChaCha20Poly1305::new(&derive_key(passphrase, salt));
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext)
+        .expect("encryption of in-memory data does not fail");
+
+    [MAGIC.as_slice(), salt, &nonce, &ciphertext].concat()
+}
+
+pub fn decrypt(data: &[u8], passphrase: &str) -> Result<Vec<u8>, EncryptionError> {
+    let data = data.strip_prefix(MAGIC).ok_or(EncryptionError::Malformed)?;
+    if data.len() < SALT_LEN + NONCE_LEN {
+        return Err(EncryptionError::Malformed);
+    }
+
+    let (salt, rest) = data.split_at(SALT_LEN);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    let salt: &[u8; SALT_LEN] = salt.try_into().map_err(|_| EncryptionError::Malformed)?;
+
+    XChaCha20Poly1305::new(&derive_key(passphrase, salt))
+        .decrypt(XNonce::from_slice(nonce), ciphertext)
+        .map_err(|_| EncryptionError::WrongPassphrase)
+}
+
+/// Derived keys by passphrase and salt.
+type KeyCache = Mutex<HashMap<(String, [u8; SALT_LEN]), Key>>;
+
+fn derive_key(passphrase: &str, salt: &[u8; SALT_LEN]) -> Key {
+    static KEYS: OnceLock<KeyCache> = OnceLock::new();
+
+    let mut keys = KEYS.get_or_init(Mutex::default).lock().unwrap();
+    *keys
+        .entry((passphrase.to_string(), *salt))
+        .or_insert_with(|| {
+            let mut key = Key::default();
+            pbkdf2::pbkdf2_hmac::<sha2::Sha256>(
+                passphrase.as_bytes(),
+                salt,
+                PBKDF2_ROUNDS,
+                &mut key,
+            );
+            key
+        })
+}
+
+/// Reads a file to a string, decrypting its `.enc` version if the plain file does not exist.
+pub fn read_to_string(path: impl AsRef<Path>) -> io::Result<String> {
+    let path = path.as_ref();
+
+    match fs::read_to_string(path) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            let data = fs::read(encrypted_path(path)).map_err(|_| e)?;
+            let plaintext = decrypt(&data, &read_passphrase()?)?;
+            String::from_utf8(plaintext).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        }
+        result => result,
+    }
+}
+
+/// Writes a file, encrypted to its `.enc` version if a passphrase is configured.
+/// The other version of the file is removed, so only one of them exists at a time.
+pub fn write(path: impl AsRef<Path>, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    let path = path.as_ref();
+    let encrypted = encrypted_path(path);
+
+    let (target, stale) = match read_passphrase() {
+        Ok(passphrase) => {
+            fs::write(&encrypted, encrypt(contents.as_ref(), &passphrase))?;
+            (encrypted, path.to_path_buf())
+        }
+        Err(_) => {
+            fs::write(path, contents)?;
+            (path.to_path_buf(), encrypted)
+        }
+    };
+
+    if target != stale && stale.exists() {
+        fs::remove_file(stale)?;
+    }
+
+    Ok(())
+}
+
+/// Returns whether the plain or encrypted version of a file exists and is not empty.
+#[must_use]
+pub fn has_content(path: impl AsRef<Path>) -> bool {
+    let path = path.as_ref();
+    fs::metadata(path).is_ok_and(|m| m.len() > 0)
+        || fs::metadata(encrypted_path(path)).is_ok_and(|m| m.len() > 0)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{EncryptionError, decrypt, encrypt, encrypted_path};
+    use std::path::Path;
+
+    #[test]
+    fn round_trips() {
+        let ciphertext = encrypt(b"1\n2\n3\n", "secret");
+        assert!(ciphertext.starts_with(b"AOC1"));
+        assert_ne!(&ciphertext[44..], b"1\n2\n3\n");
+        assert_eq!(decrypt(&ciphertext, "secret").unwrap(), b"1\n2\n3\n");
+    }
+
+    #[test]
+    fn rejects_wrong_passphrase() {
+        let ciphertext = encrypt(b"input", "secret");
+        assert!(matches!(
+            decrypt(&ciphertext, "guess"),
+            Err(EncryptionError::WrongPassphrase)
+        ));
+    }
+
+    #[test]
+    fn rejects_plain_files() {
+        assert!(matches!(
+            decrypt(b"1\n2\n3\n", "secret"),
+            Err(EncryptionError::Malformed)
+        ));
+    }
+
+    #[test]
+    fn appends_suffix() {
+        assert_eq!(
+            encrypted_path(Path::new("data/inputs/01.txt")),
+            Path::new("data/inputs/01.txt.enc")
+        );
+    }
+}
@@ -0,0 +1,305 @@
+//! Extracts example inputs from puzzle descriptions stored as markdown.
+use std::collections::HashSet;
+
+/// A fenced code block of a puzzle description.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodeBlock {
+    /// The content of the block, ending with a newline.
+    pub content: String,
+    /// The part of the puzzle the block belongs to.
+    pub part: u8,
+    /// The paragraph preceding the block.
+    pub context: String,
+}
+
+/// Minimum score for a code block to be considered an example input.
+const MIN_SCORE: i32 = 4;
+
+/// Returns all code blocks of a puzzle description in document order.
+#[must_use]
+pub fn code_blocks(md: &str) -> Vec<CodeBlock> {
+    let mut blocks = vec![];
+    let mut part = 1;
+    let mut context = String::new();
+    let mut lines = md.lines();
+
+    while let Some(line) = lines.next() {
+        if line.starts_with("```") {
+            let mut content = String::new();
+            for line in lines.by_ref() {
+                if line.starts_with("```") {
+                    break;
+                }
+                content.push_str(line);
+                content.push('\n');
+            }
+            blocks.push(CodeBlock {
+                content,
+                part,
+                context: context.clone(),
+            });
+        } else if line.starts_with("## --- Part Two") {
+            part = 2;
+            context.clear();
+        } else if !line.trim().is_empty() {
+            context = line.to_string();
+        }
+    }
+
+    blocks
+}
+
+/// Scores how much a code block looks like a puzzle input.
+///
+/// Blocks introduced as an example score higher, as do multi-line blocks.
+/// If the real input is known, blocks that use characters it does not contain score lower.
+#[must_use]
+pub fn score(block: &CodeBlock, input: Option<&str>) -> i32 {
+    let mut score = 0;
+    let context = block.context.to_lowercase();
+
+    if context.contains("example") {
+        score += 3;
+    }
+    if context.contains("consider") || context.contains("following") {
+        score += 1;
+    }
+    if block.content.lines().count() > 1 {
+        score += 2;
+    }
+    if block.content.trim().len() >= 10 {
+        score += 1;
+    }
+
+    if let Some(input) = input.filter(|input| !input.trim().is_empty()) {
+        let charset: HashSet<char> = input.chars().collect();
+        let total = block.content.chars().count().max(1);
+        let known = block
+            .content
+            .chars()
+            .filter(|c| charset.contains(c))
+            .count();
+
+        // every character of an example should appear in the real input.
+        if known == total {
+            score += 2;
+        } else if known * 10 < total * 9 {
+            score -= 4;
+        }
+    }
+
+    score
+}
+
+/// Picks the code blocks that most likely are example inputs.
+/// Returns the index of the best block of part one, followed by the best block of part two if it
+/// introduces a different example.
+#[must_use]
+pub fn pick(blocks: &[CodeBlock], input: Option<&str>) -> Vec<usize> {
+    let best_of_part = |part: u8| {
+        blocks
+            .iter()
+            .enumerate()
+            .filter(|(_, block)| block.part == part)
+            .map(|(i, block)| (i, score(block, input)))
+            .filter(|(_, score)| *score >= MIN_SCORE)
+            // prefer the first of equally scored blocks.
+            .max_by_key(|(i, score)| (*score, usize::MAX - i))
+            .map(|(i, _)| i)
+    };
+
+    let mut picked = vec![];
+
+    if let Some(first) = best_of_part(1) {
+        picked.push(first);
+    }
+
+    if let Some(second) = best_of_part(2) {
+        let is_new_example = blocks[second].context.to_lowercase().contains("example");
+        let is_duplicate = picked
+            .iter()
+            .any(|i| blocks[*i].content == blocks[second].content);
+
+        if is_new_example && !is_duplicate {
+            picked.push(second);
+        }
+    }
+
+    picked
+}
+
+/// Returns the file name of the n-th (1-based) example of a day, e.g. `01.txt` or `01-2.txt`.
+#[must_use]
+pub fn file_name(day: impl std::fmt::Display, n: usize) -> String {
+    if n == 1 {
+        format!("{day}.txt")
+    } else {
+        format!("{day}-{n}.txt")
+    }
+}
+
+/// Returns the number (1-based) of the picked example a part is tested against.
+/// Part two uses the last example introduced in its own section, or else the part one example.
+#[must_use]
+pub fn number_for_part(blocks: &[CodeBlock], picked: &[usize], part: u8) -> usize {
+    picked
+        .iter()
+        .rposition(|i| blocks[*i].part == part)
+        .map_or(1, |n| n + 1)
+}
+
+/// Rewrites the generated test of one part in the source of a day module, so that it asserts
+/// `expected` against the n-th example.
+///
+/// Only tests that still assert `None`, as generated by the template, are changed. Tests that pass
+/// [parameters](crate::template::params) to the part get the parameters of the n-th example.
+/// Returns `None` if the test was not found, was edited already or the answer is not a number.
+#[must_use]
+pub fn fill_test(source: &str, part: u8, example: usize, expected: &str) -> Option<String> {
+    let expected: u64 = expected.parse().ok()?;
+    let name = if part == 1 { "one" } else { "two" };
+
+    let start = source.find(&format!("fn test_part_{name}()"))?;
+    let end = start + source[start..].find("\n    }")?;
+    let body = &source[start..end];
+
+    if !body.contains("assert_eq!(result, None);") {
+        return None;
+    }
+
+    let read = if example == 1 {
+        "read_file(\"examples\", PUZZLE)".to_string()
+    } else {
+        format!("read_file_part(\"examples\", PUZZLE, {example})")
+    };
+
+    let body: Vec<String> = body
+        .lines()
+        .map(|line| {
+            let indent = &line[..line.len() - line.trim_start().len()];
+            if line.trim_start().starts_with("let result = ") {
+                let params = if line.contains("Params::example(") {
+                    format!(", &Params::example({example})")
+                } else {
+                    String::new()
+                };
+                format!(
+                    "{indent}let result = part_{name}(&advent_of_code::template::{read}{params});"
+                )
+            } else if line.trim_start() == "assert_eq!(result, None);" {
+                format!("{indent}assert_eq!(result, Some({expected}));")
+            } else {
+                line.to_string()
+            }
+        })
+        .collect();
+
+    Some(format!(
+        "{}{}{}",
+        &source[..start],
+        body.join("\n"),
+        &source[end..]
+    ))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{code_blocks, file_name, fill_test, number_for_part, pick};
+
+    const PUZZLE: &str = "## --- Day 11: Test ---
+
+Each line lists a device and its outputs.
+
+For example:
+
+```
+aaa: you hhh
+you: bbb ccc
+bbb: out
+```
+
+In this example, the path looks like this:
+
+```
+you -> bbb -> out
+```
+
+## --- Part Two ---
+
+Here is a different example:
+
+```
+svr: aaa bbb
+aaa: out
+```
+";
+
+    #[test]
+    fn extracts_code_blocks() {
+        let blocks = code_blocks(PUZZLE);
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(blocks[0].content, "aaa: you hhh\nyou: bbb ccc\nbbb: out\n");
+        assert_eq!(blocks[0].part, 1);
+        assert_eq!(blocks[0].context, "For example:");
+        assert_eq!(blocks[2].part, 2);
+    }
+
+    #[test]
+    fn picks_examples_of_both_parts() {
+        let blocks = code_blocks(PUZZLE);
+        assert_eq!(pick(&blocks, None), vec![0, 2]);
+    }
+
+    #[test]
+    fn uses_input_charset() {
+        let puzzle = "For example, the robots move like this:\n\n```\n>>v\n^<<\n```\n\nFor example, this map:\n\n```\n..@\n@..\n```\n";
+        let blocks = code_blocks(puzzle);
+
+        // without the input, equally scored blocks resolve to the first one.
+        assert_eq!(pick(&blocks, None), vec![0]);
+        // the movement diagram uses characters the input does not contain.
+        assert_eq!(pick(&blocks, Some("@.@\n...\n")), vec![1]);
+    }
+
+    #[test]
+    fn names_example_files() {
+        assert_eq!(file_name("01", 1), "01.txt");
+        assert_eq!(file_name("01", 2), "01-2.txt");
+    }
+
+    #[test]
+    fn maps_parts_to_examples() {
+        let blocks = code_blocks(PUZZLE);
+        assert_eq!(number_for_part(&blocks, &[0, 2], 1), 1);
+        assert_eq!(number_for_part(&blocks, &[0, 2], 2), 2);
+        assert_eq!(number_for_part(&blocks, &[0], 2), 1);
+    }
+
+    #[test]
+    fn fills_generated_tests() {
+        let template = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+
+        let source = fill_test(template, 2, 2, "2").unwrap();
+        assert!(source.contains(
+            "let result = part_two(&advent_of_code::template::read_file_part(\"examples\", PUZZLE, 2));\n        assert_eq!(result, Some(2));"
+        ));
+
+        let source = fill_test(&source, 1, 1, "40").unwrap();
+        assert!(source.contains("assert_eq!(result, Some(40));"));
+
+        let with_params = template.replace(
+            "read_file(\"examples\", PUZZLE));",
+            "read_file(\"examples\", PUZZLE), &Params::example(1));",
+        );
+        let filled = fill_test(&with_params, 2, 2, "2").unwrap();
+        assert!(filled.contains(
+            "let result = part_two(&advent_of_code::template::read_file_part(\"examples\", PUZZLE, 2), &Params::example(2));"
+        ));
+
+        // filled or non-numeric tests are left alone.
+        assert_eq!(fill_test(&source, 1, 1, "41"), None);
+        assert_eq!(fill_test(template, 1, 1, "abc"), None);
+    }
+}
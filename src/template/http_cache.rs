@@ -0,0 +1,130 @@
+/// A local cache for pages fetched from the Advent of Code website.
+/// Every page is stored next to a JSON file that records its url and fetch time.
+use std::{
+    collections::HashMap,
+    fs,
+    io::Error,
+    path::PathBuf,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::{config, encryption};
+
+/// Name of the cache directory within the data directory, shared by all years.
+const CACHE_DIR: &str = ".cache";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CachedPage {
+    pub body: String,
+    pub fetched_at: SystemTime,
+}
+
+pub struct HttpCache {
+    dir: PathBuf,
+}
+
+impl Default for HttpCache {
+    fn default() -> Self {
+        Self::new(config::get().paths.data.join(CACHE_DIR))
+    }
+}
+
+impl HttpCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Returns the cached page for a url path, if present.
+    pub fn get(&self, path: &str) -> Option<CachedPage> {
+        let (body_path, meta_path) = self.paths(path);
+
+        let meta = fs::read_to_string(meta_path).ok()?;
+        let json = JsonValue::from_str(&meta).ok()?;
+        let secs = json
+            .get::<HashMap<String, JsonValue>>()?
+            .get("fetched_at")?
+            .get::<f64>()
+            .copied()?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let fetched_at = UNIX_EPOCH + Duration::from_secs(secs as u64);
+
+        Some(CachedPage {
+            body: encryption::read_to_string(body_path).ok()?,
+            fetched_at,
+        })
+    }
+
+    /// Stores a page for a url path.
+    pub fn put(&self, path: &str, body: &str, fetched_at: SystemTime) -> Result<(), Error> {
+        let (body_path, meta_path) = self.paths(path);
+        fs::create_dir_all(&self.dir)?;
+
+        // the cache can live in any configured data directory, so it ignores itself.
+        let gitignore = self.dir.join(".gitignore");
+        if !gitignore.exists() {
+            fs::write(gitignore, "*\n")?;
+        }
+
+        let secs = fetched_at
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        map.insert("url".into(), JsonValue::String(path.into()));
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("fetched_at".into(), JsonValue::Number(secs as f64));
+
+        encryption::write(body_path, body)?;
+        let mut file = fs::File::create(meta_path)?;
+        JsonValue::Object(map).format_to(&mut file)
+    }
+
+    fn paths(&self, path: &str) -> (PathBuf, PathBuf) {
+        let key: String = path
+            .trim_matches('/')
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+            .collect();
+
+        (
+            self.dir.join(format!("{key}.html")),
+            self.dir.join(format!("{key}.json")),
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::HttpCache;
+    use crate::template::temp_dir::TempDir;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn stores_pages_with_fetch_time() {
+        let dir = TempDir::new("http-cache");
+        let cache = HttpCache::new(dir.path());
+        let fetched_at = UNIX_EPOCH + Duration::from_secs(1_764_565_200);
+
+        cache
+            .put("/2025/day/1", "<main></main>", fetched_at)
+            .unwrap();
+
+        let page = cache.get("/2025/day/1").unwrap();
+        assert_eq!(page.body, "<main></main>");
+        assert_eq!(page.fetched_at, fetched_at);
+        assert!(dir.path().join(".gitignore").exists());
+    }
+
+    #[test]
+    fn misses_unknown_pages() {
+        let dir = TempDir::new("http-cache");
+        let cache = HttpCache::new(dir.path());
+        assert_eq!(cache.get("/2025/day/25"), None);
+    }
+}
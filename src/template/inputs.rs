@@ -0,0 +1,175 @@
+//! Puzzle inputs of a day: our own `data/inputs/NN.txt` plus any number of named inputs in
+//! `data/inputs/NN/<name>.txt`, e.g. of other team members. In the year-qualified layout, these
+//! live in `data/YYYY/inputs`.
+//!
+//! Expected answers for named inputs are stored in `data/inputs/NN/answers.json`:
+//! `{ "alice": { "part_1": "42", "part_2": null } }`.
+use std::{collections::HashMap, fs, path::PathBuf, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::answers::Answers;
+use crate::template::run_multi::child_commands::{self, PartOutput};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, Puzzle, encryption};
+
+/// Name of our own input, `data/inputs/NN.txt`.
+pub const DEFAULT_INPUT: &str = "default";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NamedInput {
+    pub name: String,
+    pub path: PathBuf,
+    pub expected: [Option<String>; 2],
+}
+
+/// Lists all inputs of a day with their expected answers, our own input first.
+/// Our own input is expected to produce the answers accepted by the website.
+pub fn list(day: Day, answers: &Answers) -> Vec<NamedInput> {
+    let mut inputs = vec![];
+
+    let puzzle = Puzzle::current(day);
+    let default_path = PathBuf::from(puzzle.input_path());
+    if encryption::has_content(&default_path) {
+        let answer = answers.get(day);
+        inputs.push(NamedInput {
+            name: DEFAULT_INPUT.into(),
+            path: default_path,
+            expected: [answer.part_1, answer.part_2],
+        });
+    }
+
+    let dir = puzzle.inputs_dir();
+    let expected = fs::read_to_string(dir.join("answers.json"))
+        .map_err(|e| e.to_string())
+        .and_then(parse_expected)
+        .unwrap_or_default();
+
+    let mut named: Vec<NamedInput> = fs::read_dir(&dir)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        // encrypted inputs are read through their plain path.
+        .map(|path| {
+            match path
+                .to_str()
+                .and_then(|p| p.strip_suffix(encryption::SUFFIX))
+            {
+                Some(plain) => PathBuf::from(plain),
+                None => path,
+            }
+        })
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .filter_map(|path| {
+            let name = path.file_stem()?.to_string_lossy().to_string();
+            Some(NamedInput {
+                expected: expected.get(&name).cloned().unwrap_or_default(),
+                name,
+                path,
+            })
+        })
+        .collect();
+
+    named.sort_by(|a, b| a.name.cmp(&b.name));
+    named.dedup_by(|a, b| a.name == b.name);
+    inputs.extend(named);
+    inputs
+}
+
+/// Parses the expected answers of named inputs.
+fn parse_expected(json: String) -> Result<HashMap<String, [Option<String>; 2]>, String> {
+    let json = JsonValue::from_str(&json).or(Err("not valid JSON file."))?;
+
+    json.get::<HashMap<String, JsonValue>>()
+        .ok_or("expected JSON document to be an object.")?
+        .iter()
+        .map(|(name, answers)| {
+            let answers = answers
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or(format!("expected answers of `{name}` to be an object."))?;
+            let part = |key: &str| answers.get(key).and_then(|v| v.get::<String>()).cloned();
+            Ok((name.clone(), [part("part_1"), part("part_2")]))
+        })
+        .collect()
+}
+
+/// Runs a day's solution over every input and compares the results with the expected answers.
+/// Returns a description of every mismatch, and of every input the solution could not run on.
+pub fn check_all(day: Day, inputs: &[NamedInput], is_release: bool) -> Vec<String> {
+    let mut mismatches = vec![];
+
+    for input in inputs {
+        println!("{ANSI_BOLD}[{}]{ANSI_RESET}", input.name);
+
+        let output = match child_commands::run_solution(day, false, is_release, Some(&input.path)) {
+            Ok(output) => output,
+            Err(e) => {
+                mismatches.push(format!(
+                    "Day {day} [{}]: could not run solution: {e}",
+                    input.name
+                ));
+                continue;
+            }
+        };
+        let results = child_commands::parse_results(&output);
+
+        mismatches.extend(compare(day, input, &results));
+    }
+
+    mismatches
+}
+
+fn compare(day: Day, input: &NamedInput, results: &[PartOutput; 2]) -> Vec<String> {
+    (1..=2)
+        .zip(input.expected.iter().zip(results))
+        .filter_map(|(part, (expected, result))| {
+            let expected = expected.as_deref()?;
+            (result.answer() != Some(expected)).then(|| {
+                format!(
+                    "Day {day} [{}] part {part}: expected {expected}, got {result}",
+                    input.name
+                )
+            })
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{NamedInput, compare, parse_expected};
+    use crate::day;
+    use crate::template::run_multi::child_commands::PartOutput;
+
+    #[test]
+    fn parses_expected_answers() {
+        let expected =
+            parse_expected(r#"{ "alice": { "part_1": "1", "part_2": null } }"#.into()).unwrap();
+        assert_eq!(expected["alice"], [Some("1".to_string()), None]);
+    }
+
+    #[test]
+    fn reports_mismatches_per_input() {
+        let input = NamedInput {
+            name: "alice".into(),
+            path: "data/inputs/01/alice.txt".into(),
+            expected: [Some("1".into()), Some("2".into())],
+        };
+
+        let answer = |answer: &str| PartOutput::Answer(answer.into());
+
+        assert!(compare(day!(1), &input, &[answer("1"), answer("2")]).is_empty());
+        assert_eq!(
+            compare(day!(1), &input, &[answer("1"), PartOutput::Missing]),
+            vec!["Day 01 [alice] part 2: expected 2, got nothing"]
+        );
+        assert_eq!(
+            compare(
+                day!(1),
+                &input,
+                &[PartOutput::Error("bad input".into()), answer("2")]
+            ),
+            vec!["Day 01 [alice] part 1: expected 1, got error \"bad input\""]
+        );
+    }
+}
@@ -0,0 +1,282 @@
+//! Sanity checks for downloaded inputs, so an error page does not end up in `parse`.
+//!
+//! A checksum of every downloaded input is stored in `data/checksums.json`, which allows warning
+//! about inputs that were changed or truncated on disk after the download.
+use sha2::{Digest, Sha256};
+use std::{collections::HashMap, fmt::Display, fs, io::Error, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Puzzle, layout};
+
+const CHECKSUMS_FILE: &str = "checksums.json";
+
+/// Inputs larger than this are most likely not a puzzle input.
+const MAX_INPUT_SIZE: usize = 1024 * 1024;
+
+/// Texts the website serves instead of an input.
+const ERROR_MESSAGES: [&str; 3] = [
+    "Puzzle inputs differ by user.",
+    "Please don't repeatedly request this endpoint before it unlocks!",
+    "404 Not Found",
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Problem {
+    Empty,
+    Html,
+    ErrorMessage(String),
+    MissingNewline,
+    TooLarge(usize),
+}
+
+impl Problem {
+    /// Inputs with fatal problems can not be solved.
+    #[must_use]
+    pub fn is_fatal(&self) -> bool {
+        matches!(
+            self,
+            Problem::Empty | Problem::Html | Problem::ErrorMessage(_)
+        )
+    }
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Problem::Empty => write!(f, "input is empty."),
+            Problem::Html => write!(
+                f,
+                "input is an HTML page, is the session cookie valid and the day unlocked?"
+            ),
+            Problem::ErrorMessage(message) => {
+                write!(f, "input is an error message of the website: \"{message}\"")
+            }
+            Problem::MissingNewline => write!(
+                f,
+                "input does not end with a newline, the download may be truncated."
+            ),
+            Problem::TooLarge(len) => write!(f, "input is suspiciously large ({len} bytes)."),
+        }
+    }
+}
+
+/// Returns the problems of an input, fatal problems first.
+#[must_use]
+pub fn inspect(input: &str) -> Vec<Problem> {
+    let trimmed = input.trim();
+
+    if trimmed.is_empty() {
+        return vec![Problem::Empty];
+    }
+
+    let lowercase = trimmed.get(..64).unwrap_or(trimmed).to_lowercase();
+    if lowercase.starts_with("<!doctype html") || lowercase.starts_with("<html") {
+        return vec![Problem::Html];
+    }
+
+    if let Some(message) = ERROR_MESSAGES.iter().find(|m| trimmed.starts_with(*m)) {
+        return vec![Problem::ErrorMessage((*message).to_string())];
+    }
+
+    let mut problems = vec![];
+    if !input.ends_with('\n') {
+        problems.push(Problem::MissingNewline);
+    }
+    if input.len() > MAX_INPUT_SIZE {
+        problems.push(Problem::TooLarge(input.len()));
+    }
+    problems
+}
+
+#[must_use]
+pub fn checksum(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// Represents the checksums of downloaded inputs.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Checksums {
+    pub data: HashMap<Day, String>,
+}
+
+impl Checksums {
+    /// Dehydrate checksums to a JSON file of a year.
+    pub fn store_file(&self, year: u16) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(layout::store_path(year, CHECKSUMS_FILE))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate checksums from a JSON file of a year. If not present, returns empty checksums.
+    pub fn read_from_file(year: u16) -> Self {
+        fs::read_to_string(layout::store_path(year, CHECKSUMS_FILE))
+            .map_err(|x| x.to_string())
+            .and_then(Checksums::try_from)
+            .unwrap_or_default()
+    }
+
+    /// Records the checksum of a freshly downloaded input.
+    pub fn record(puzzle: Puzzle, input: &str) -> Result<(), Error> {
+        let mut checksums = Self::read_from_file(puzzle.year);
+        checksums.data.insert(puzzle.day, checksum(input));
+        checksums.store_file(puzzle.year)
+    }
+
+    /// Returns whether an input differs from the one downloaded. Unknown inputs are not reported.
+    #[must_use]
+    pub fn is_modified(&self, day: Day, input: &str) -> bool {
+        self.data
+            .get(&day)
+            .is_some_and(|expected| *expected != checksum(input))
+    }
+}
+
+/// Prints the problems of a puzzle's input before solving it and exits on fatal ones.
+/// Only downloaded inputs have a checksum to compare with.
+pub fn check_input(puzzle: Puzzle, input: &str, is_downloaded: bool) {
+    let day = puzzle.day;
+    let problems = inspect(input);
+
+    for problem in &problems {
+        eprintln!("⚠️  Day {day}: {problem}");
+    }
+
+    if problems.iter().any(Problem::is_fatal) {
+        eprintln!("Run `cargo download {day} --overwrite` to download the input again.");
+        std::process::exit(1);
+    }
+
+    if is_downloaded && Checksums::read_from_file(puzzle.year).is_modified(day, input) {
+        eprintln!(
+            "⚠️  Day {day}: input does not match the checksum recorded at download. \
+            Run `cargo download {day} --overwrite` if this is unintended."
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Checksums> for JsonValue {
+    fn from(value: Checksums) -> Self {
+        let mut data: Vec<(&Day, &String)> = value.data.iter().collect();
+        data.sort_unstable_by_key(|(day, _)| **day);
+
+        let data = data
+            .into_iter()
+            .map(|(day, sha256)| {
+                let mut map: HashMap<String, JsonValue> = HashMap::new();
+                map.insert("day".into(), JsonValue::String(day.to_string()));
+                map.insert("sha256".into(), JsonValue::String(sha256.clone()));
+                JsonValue::Object(map)
+            })
+            .collect();
+
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        map.insert("data".into(), JsonValue::Array(data));
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Checksums {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        let data = json_data
+            .iter()
+            .map(|entry| {
+                let entry = entry
+                    .get::<HashMap<String, JsonValue>>()
+                    .ok_or("Expected checksum to be a JSON object.")?;
+
+                let day = entry
+                    .get("day")
+                    .and_then(|v| v.get::<String>())
+                    .and_then(|day| Day::parse_any(day))
+                    .ok_or("Expected checksum.day to be a Day struct.")?;
+
+                let sha256 = entry
+                    .get("sha256")
+                    .and_then(|v| v.get::<String>())
+                    .ok_or("Expected checksum.sha256 to be a string.")?;
+
+                Ok((day, sha256.clone()))
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(Checksums { data })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Checksums, Problem, checksum, inspect};
+    use crate::day;
+
+    #[test]
+    fn accepts_regular_input() {
+        assert!(inspect("1,2\n3,4\n").is_empty());
+    }
+
+    #[test]
+    fn detects_error_pages() {
+        assert_eq!(inspect(" \n"), vec![Problem::Empty]);
+        assert_eq!(
+            inspect("<!DOCTYPE html>\n<html lang=\"en-us\">"),
+            vec![Problem::Html]
+        );
+        assert!(matches!(
+            inspect("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n")[..],
+            [Problem::ErrorMessage(_)]
+        ));
+        assert!(inspect("<html>").iter().all(Problem::is_fatal));
+    }
+
+    #[test]
+    fn warns_about_suspicious_inputs() {
+        assert_eq!(inspect("1,2\n3,"), vec![Problem::MissingNewline]);
+        assert_eq!(
+            inspect(&"1\n".repeat(600_000)),
+            vec![Problem::TooLarge(1_200_000)]
+        );
+        assert!(!Problem::MissingNewline.is_fatal());
+    }
+
+    #[test]
+    fn detects_modified_inputs() {
+        let mut checksums = Checksums::default();
+        checksums.data.insert(day!(1), checksum("1\n2\n"));
+
+        assert!(!checksums.is_modified(day!(1), "1\n2\n"));
+        assert!(checksums.is_modified(day!(1), "1\n"));
+        assert!(!checksums.is_modified(day!(2), "1\n"));
+    }
+
+    #[test]
+    fn round_trips_json() {
+        let mut checksums = Checksums::default();
+        checksums.data.insert(day!(3), checksum(""));
+
+        let json = tinyjson::JsonValue::from(checksums).stringify().unwrap();
+        let checksums = Checksums::try_from(json).unwrap();
+        assert_eq!(
+            checksums.data[&day!(3)],
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+}
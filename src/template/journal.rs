@@ -0,0 +1,265 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::Error,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::leaderboard::format_duration;
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, Puzzle, layout};
+
+const JOURNAL_FILE: &str = "journal.json";
+
+/// Something worth remembering the time of.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Event {
+    Scaffolded,
+    Solved(u8),
+}
+
+/// Represents the moments (unix seconds) a day was scaffolded and its parts were accepted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    pub day: Day,
+    pub scaffolded_at: Option<u64>,
+    pub part_1_at: Option<u64>,
+    pub part_2_at: Option<u64>,
+}
+
+/// Represents the personal solve times for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Journal {
+    pub data: Vec<Entry>,
+}
+
+impl Journal {
+    /// Dehydrate the journal to a JSON file of a year.
+    pub fn store_file(&self, year: u16) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(layout::store_path(year, JOURNAL_FILE))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate the journal from a JSON file of a year. If not present, returns an empty journal.
+    pub fn read_from_file(year: u16) -> Self {
+        fs::read_to_string(layout::store_path(year, JOURNAL_FILE))
+            .map_err(|x| x.to_string())
+            .and_then(Journal::try_from)
+            .unwrap_or_default()
+    }
+
+    /// Records an event in the journal file of the puzzle's year.
+    /// Only the first occurrence of an event is kept.
+    pub fn record(puzzle: Puzzle, event: Event, at: SystemTime) -> Result<(), Error> {
+        let mut journal = Self::read_from_file(puzzle.year);
+        if journal.set(puzzle.day, event, at) {
+            journal.store_file(puzzle.year)?;
+        }
+        Ok(())
+    }
+
+    fn set(&mut self, day: Day, event: Event, at: SystemTime) -> bool {
+        let at = at.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+
+        let index = match self.data.iter().position(|entry| entry.day == day) {
+            Some(index) => index,
+            None => {
+                self.data.push(Entry {
+                    day,
+                    scaffolded_at: None,
+                    part_1_at: None,
+                    part_2_at: None,
+                });
+                self.data.sort_unstable_by_key(|entry| entry.day);
+                self.data.iter().position(|entry| entry.day == day).unwrap()
+            }
+        };
+
+        let entry = &mut self.data[index];
+        let slot = match event {
+            Event::Scaffolded => &mut entry.scaffolded_at,
+            Event::Solved(1) => &mut entry.part_1_at,
+            Event::Solved(2) => &mut entry.part_2_at,
+            Event::Solved(_) => return false,
+        };
+
+        if slot.is_some() {
+            return false;
+        }
+        *slot = Some(at);
+        true
+    }
+
+    /// Renders the time from unlock to scaffolding and to each star, and from part 1 to part 2.
+    #[must_use]
+    pub fn render(&self, year: u16) -> String {
+        let mut lines = vec![format!(
+            "{ANSI_BOLD}Day {:>12} {:>12} {:>12} {:>12}{ANSI_RESET}",
+            "Scaffold", "Part 1", "Part 2", "Delta"
+        )];
+
+        for entry in &self.data {
+            let unlock = entry
+                .day
+                .unlock_time(year)
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs();
+            let since_unlock = |at: Option<u64>| at.map(|at| at.saturating_sub(unlock));
+            let delta = entry
+                .part_1_at
+                .zip(entry.part_2_at)
+                .map(|(p1, p2)| p2.saturating_sub(p1));
+
+            lines.push(format!(
+                "{:>3} {:>12} {:>12} {:>12} {:>12}",
+                entry.day.to_string(),
+                format_duration(since_unlock(entry.scaffolded_at)),
+                format_duration(since_unlock(entry.part_1_at)),
+                format_duration(since_unlock(entry.part_2_at)),
+                format_duration(delta),
+            ));
+        }
+
+        lines.join("\n")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Journal> for JsonValue {
+    fn from(value: Journal) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Journal {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Journal {
+            data: json_data
+                .iter()
+                .map(Entry::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Entry> for JsonValue {
+    fn from(value: &Entry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+
+        for (key, at) in [
+            ("scaffolded_at", value.scaffolded_at),
+            ("part_1_at", value.part_1_at),
+            ("part_2_at", value.part_2_at),
+        ] {
+            #[allow(clippy::cast_precision_loss)]
+            let at = at.map_or(JsonValue::Null, |at| JsonValue::Number(at as f64));
+            map.insert(key.into(), at);
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Entry {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected journal entry to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::parse_any(day))
+            .ok_or("Expected entry.day to be a Day struct.")?;
+
+        let at = |key: &str| -> Result<Option<u64>, String> {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            json.get(key)
+                .map(|v| v.get::<f64>().map(|at| *at as u64))
+                .ok_or(format!("Expected entry.{key} to be null or a number."))
+        };
+
+        Ok(Entry {
+            day,
+            scaffolded_at: at("scaffolded_at")?,
+            part_1_at: at("part_1_at")?,
+            part_2_at: at("part_2_at")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Event, Journal};
+    use crate::day;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    // 2025-12-01 05:00:00 UTC is the unlock of day 1.
+    const UNLOCK: u64 = 1_764_565_200;
+
+    fn at(secs: u64) -> std::time::SystemTime {
+        UNIX_EPOCH + Duration::from_secs(UNLOCK + secs)
+    }
+
+    #[test]
+    fn keeps_first_occurrence() {
+        let mut journal = Journal::default();
+        assert!(journal.set(day!(1), Event::Solved(1), at(60)));
+        assert!(!journal.set(day!(1), Event::Solved(1), at(120)));
+        assert_eq!(journal.data[0].part_1_at, Some(UNLOCK + 60));
+    }
+
+    #[test]
+    fn round_trips_json() {
+        let mut journal = Journal::default();
+        journal.set(day!(2), Event::Scaffolded, at(5));
+
+        let json = tinyjson::JsonValue::from(journal).stringify().unwrap();
+        let journal = Journal::try_from(json).unwrap();
+        assert_eq!(journal.data[0].scaffolded_at, Some(UNLOCK + 5));
+        assert_eq!(journal.data[0].part_1_at, None);
+    }
+
+    #[test]
+    fn renders_times_relative_to_unlock() {
+        let mut journal = Journal::default();
+        journal.set(day!(1), Event::Scaffolded, at(10));
+        journal.set(day!(1), Event::Solved(1), at(600));
+        journal.set(day!(1), Event::Solved(2), at(3_600 + 600));
+
+        let rendered = journal.render(2025);
+        assert!(rendered.ends_with(" 01     00:00:10     00:10:00     01:10:00     01:00:00"));
+    }
+}
@@ -0,0 +1,247 @@
+//! Where the solution and the data of a puzzle live.
+//!
+//! Repositories start out with the flat layout of a single year, where files are keyed by day:
+//! `src/bin/01.rs`, `data/inputs/01.txt`, `data/timings.json`.
+//! Repositories with several years use the year-qualified layout instead:
+//! `src/bin/2025-01.rs`, `data/2025/inputs/01.txt`, `data/2025/timings.json`.
+//! `cargo migrate` moves a flat layout under its year.
+//!
+//! Commands work on the year set in `aoc.toml`, which can be overridden for a single command,
+//! e.g. `AOC_YEAR=2024 cargo solve 1`.
+use std::{fs, path::PathBuf, time::SystemTime};
+
+use crate::template::{Day, config, examples};
+
+/// Cargo builds every file in this directory as a binary.
+pub(crate) const SOLUTIONS_DIR: &str = "src/bin";
+
+/// The first year of advent of code.
+pub(crate) const FIRST_YEAR: u16 = 2015;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Layout {
+    Flat,
+    YearQualified,
+}
+
+impl Layout {
+    /// The year-qualified layout is used as soon as `data` contains a year directory.
+    #[must_use]
+    pub fn detect() -> Self {
+        if years().is_empty() {
+            Layout::Flat
+        } else {
+            Layout::YearQualified
+        }
+    }
+
+    /// Returns the data directory of a year, e.g. `data/2025`.
+    #[must_use]
+    pub fn data_dir(self, year: u16) -> PathBuf {
+        match self {
+            Layout::Flat => config::get().paths.data.clone(),
+            Layout::YearQualified => config::get().paths.data.join(year.to_string()),
+        }
+    }
+
+    /// Returns the name of the solution binary of a puzzle, e.g. `01` or `2025-01`.
+    #[must_use]
+    pub fn bin_name(self, puzzle: Puzzle) -> String {
+        match self {
+            Layout::Flat => puzzle.day.to_string(),
+            Layout::YearQualified => format!("{}-{}", puzzle.year, puzzle.day),
+        }
+    }
+}
+
+/// Returns the years stored in the year-qualified layout in ascending order.
+#[must_use]
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = fs::read_dir(&config::get().paths.data)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| parse_year(entry.file_name().to_str()?))
+        .collect();
+
+    years.sort_unstable();
+    years
+}
+
+/// Returns the year commands work on: the configured year, or the latest year with puzzles.
+#[must_use]
+pub fn active_year() -> u16 {
+    config::get()
+        .year
+        .unwrap_or_else(|| latest_year(SystemTime::now()))
+}
+
+fn latest_year(now: SystemTime) -> u16 {
+    let first_day = crate::day!(1);
+    (FIRST_YEAR..)
+        .take_while(|year| first_day.is_unlocked(*year, now))
+        .last()
+        .unwrap_or(FIRST_YEAR)
+}
+
+/// Returns the path of a file that is stored per year, e.g. `data/2025/timings.json`.
+#[must_use]
+pub fn store_path(year: u16, file_name: &str) -> PathBuf {
+    Layout::detect().data_dir(year).join(file_name)
+}
+
+pub(crate) fn parse_year(s: &str) -> Option<u16> {
+    if s.len() != 4 || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    s.parse().ok().filter(|year| *year >= FIRST_YEAR)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Identifies a puzzle by its year and day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: Day,
+}
+
+impl Puzzle {
+    #[must_use]
+    pub const fn new(year: u16, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    /// Returns the puzzle of a day in the [active year](active_year).
+    #[must_use]
+    pub fn current(day: Day) -> Self {
+        Self::new(active_year(), day)
+    }
+
+    /// Returns the puzzle a solution binary is built for. Year-qualified binaries are named like
+    /// `2025-01`, binaries of the flat layout belong to the [active year](active_year).
+    #[must_use]
+    pub fn for_bin(bin_name: &str, day: Day) -> Self {
+        match bin_name.split_once('-') {
+            Some((year, _)) => {
+                parse_year(year).map_or_else(|| Self::current(day), |year| Self::new(year, day))
+            }
+            None => Self::current(day),
+        }
+    }
+
+    /// Returns the name of the solution binary, e.g. `01` or `2025-01`.
+    #[must_use]
+    pub fn bin_name(self) -> String {
+        Layout::detect().bin_name(self)
+    }
+
+    #[must_use]
+    pub fn module_path(self) -> String {
+        format!("{SOLUTIONS_DIR}/{}.rs", self.bin_name())
+    }
+
+    #[must_use]
+    pub fn data_dir(self) -> PathBuf {
+        Layout::detect().data_dir(self.year)
+    }
+
+    #[must_use]
+    pub fn input_path(self) -> String {
+        self.data_file("inputs", &format!("{}.txt", self.day))
+    }
+
+    /// Returns the path of the n-th example, e.g. `data/examples/01-2.txt`.
+    #[must_use]
+    pub fn example_path(self, n: usize) -> String {
+        self.data_file("examples", &examples::file_name(self.day, n))
+    }
+
+    /// Returns the path of the parameters of the examples, e.g. `data/examples/01.toml`.
+    /// See [`params`](crate::template::params).
+    #[must_use]
+    pub fn example_manifest_path(self) -> String {
+        self.data_file("examples", &format!("{}.toml", self.day))
+    }
+
+    #[must_use]
+    pub fn puzzle_path(self) -> String {
+        self.data_file("puzzles", &format!("{}.md", self.day))
+    }
+
+    /// Returns the directory of named inputs, e.g. `data/inputs/01`.
+    #[must_use]
+    pub fn inputs_dir(self) -> PathBuf {
+        self.data_dir().join("inputs").join(self.day.to_string())
+    }
+
+    fn data_file(self, folder: &str, file_name: &str) -> String {
+        self.data_dir()
+            .join(folder)
+            .join(file_name)
+            .to_string_lossy()
+            .to_string()
+    }
+}
+
+impl From<Day> for Puzzle {
+    fn from(day: Day) -> Self {
+        Self::current(day)
+    }
+}
+
+/// The puzzle of a solution binary, which is only known once the config is read at runtime.
+/// See [`Puzzle::for_bin`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BinPuzzle {
+    bin_name: &'static str,
+    day: Day,
+}
+
+impl BinPuzzle {
+    #[must_use]
+    pub const fn new(bin_name: &'static str, day: Day) -> Self {
+        Self { bin_name, day }
+    }
+}
+
+impl From<BinPuzzle> for Puzzle {
+    fn from(bin: BinPuzzle) -> Self {
+        Self::for_bin(bin.bin_name, bin.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Puzzle, latest_year, parse_year};
+    use crate::day;
+    use std::time::Duration;
+
+    #[test]
+    fn parses_years() {
+        assert_eq!(parse_year("2025"), Some(2025));
+        assert_eq!(parse_year("1999"), None);
+        assert_eq!(parse_year("25"), None);
+        assert_eq!(parse_year("20x5"), None);
+    }
+
+    #[test]
+    fn reads_year_of_bin() {
+        assert_eq!(
+            Puzzle::for_bin("2024-03", day!(3)),
+            Puzzle::new(2024, day!(3))
+        );
+        assert_eq!(Puzzle::for_bin("03", day!(3)), Puzzle::current(day!(3)));
+    }
+
+    #[test]
+    fn finds_latest_year() {
+        let unlock = day!(1).unlock_time(2025);
+        assert_eq!(latest_year(unlock), 2025);
+        assert_eq!(latest_year(unlock - Duration::from_secs(1)), 2024);
+    }
+}
@@ -0,0 +1,326 @@
+//! Private leaderboards as served by `/{year}/leaderboard/private/view/{id}.json`.
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    str::FromStr,
+    time::UNIX_EPOCH,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, days_in_year};
+
+/// Star timestamps (unix seconds) of one member for one day.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DayStars {
+    pub part_1: Option<u64>,
+    pub part_2: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    pub name: String,
+    pub local_score: u64,
+    pub stars: u64,
+    pub days: BTreeMap<Day, DayStars>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub year: u16,
+    pub members: Vec<Member>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortBy {
+    LocalScore,
+    Stars,
+}
+
+impl FromStr for SortBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "score" => Ok(SortBy::LocalScore),
+            "stars" => Ok(SortBy::Stars),
+            _ => Err(format!(
+                "unknown sort order `{s}`, expected `score` or `stars`."
+            )),
+        }
+    }
+}
+
+impl Leaderboard {
+    /// Orders the members by the given criterion, using the other one to break ties.
+    pub fn sort(&mut self, by: SortBy) {
+        self.members.sort_by(|a, b| {
+            let key = |m: &Member| match by {
+                SortBy::LocalScore => (m.local_score, m.stars),
+                SortBy::Stars => (m.stars, m.local_score),
+            };
+            key(b).cmp(&key(a)).then_with(|| a.name.cmp(&b.name))
+        });
+    }
+
+    /// Renders the rankings, followed by the star times of every day relative to its unlock.
+    #[must_use]
+    pub fn render(&self) -> String {
+        let width = self
+            .members
+            .iter()
+            .map(|m| m.name.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max(4);
+
+        let mut out = format!("{ANSI_BOLD}  # {:width$} Score Stars{ANSI_RESET}\n", "Name");
+        for (rank, member) in self.members.iter().enumerate() {
+            out += &format!(
+                "{:>3} {:width$} {:>5} {:>5}\n",
+                rank + 1,
+                member.name,
+                member.local_score,
+                member.stars
+            );
+        }
+
+        let days: Vec<Day> = self
+            .members
+            .iter()
+            .flat_map(|m| m.days.keys().copied())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        for day in days {
+            let unlock = day
+                .unlock_time(self.year)
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs();
+            let since_unlock = |ts: Option<u64>| ts.map(|ts| ts.saturating_sub(unlock));
+
+            out += &format!(
+                "\n{ANSI_BOLD}{:width$} {:>12} {:>12} {:>12}{ANSI_RESET}\n",
+                format!("Day {day}"),
+                "Part 1",
+                "Part 2",
+                "Delta",
+            );
+
+            for member in &self.members {
+                let Some(stars) = member.days.get(&day) else {
+                    continue;
+                };
+                let delta = stars
+                    .part_1
+                    .zip(stars.part_2)
+                    .map(|(p1, p2)| p2.saturating_sub(p1));
+
+                out += &format!(
+                    "{:width$} {:>12} {:>12} {:>12}\n",
+                    member.name,
+                    format_duration(since_unlock(stars.part_1)),
+                    format_duration(since_unlock(stars.part_2)),
+                    format_duration(delta),
+                );
+            }
+        }
+
+        out
+    }
+}
+
+/// Formats seconds as `hh:mm:ss`, prefixed with the number of days if longer than a day.
+pub(crate) fn format_duration(secs: Option<u64>) -> String {
+    let Some(secs) = secs else {
+        return "-".into();
+    };
+
+    let (days, secs) = (secs / 86400, secs % 86400);
+    let time = format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60);
+
+    if days > 0 {
+        format!("{days}d {time}")
+    } else {
+        time
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl TryFrom<String> for Leaderboard {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON."))?;
+
+        let root = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let year = root
+            .get("event")
+            .and_then(|event| event.get::<String>())
+            .and_then(|event| event.parse().ok())
+            .ok_or("expected JSON document to have a numeric `event`.")?;
+
+        let members = root
+            .get("members")
+            .and_then(|members| members.get::<HashMap<String, JsonValue>>())
+            .ok_or("expected JSON document to have an object `members`.")?
+            .values()
+            .map(|member| Member::from_json(member, year))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut leaderboard = Leaderboard { year, members };
+        leaderboard.sort(SortBy::LocalScore);
+        Ok(leaderboard)
+    }
+}
+
+impl Member {
+    /// Parses a member of the leaderboard of `year`.
+    fn from_json(value: &JsonValue, year: u16) -> Result<Self, String> {
+        let map = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected member to be an object.")?;
+
+        let number = |key: &str| -> Result<u64, String> {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            map.get(key)
+                .and_then(|v| v.get::<f64>())
+                .map(|v| *v as u64)
+                .ok_or(format!("expected member to have a numeric `{key}`."))
+        };
+
+        let id = number("id")?;
+        let name = map
+            .get("name")
+            .and_then(|name| name.get::<String>())
+            .cloned()
+            .unwrap_or_else(|| format!("(anonymous user #{id})"));
+
+        let mut days = BTreeMap::new();
+        if let Some(levels) = map
+            .get("completion_day_level")
+            .and_then(|levels| levels.get::<HashMap<String, JsonValue>>())
+        {
+            for (day, parts) in levels {
+                let day = day
+                    .parse()
+                    .ok()
+                    .and_then(Day::new)
+                    .filter(|day| day.into_inner() <= days_in_year(year))
+                    .ok_or(format!("invalid day `{day}`."))?;
+                let star = |part: &str| {
+                    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                    parts
+                        .get::<HashMap<String, JsonValue>>()?
+                        .get(part)?
+                        .get::<HashMap<String, JsonValue>>()?
+                        .get("get_star_ts")?
+                        .get::<f64>()
+                        .map(|ts| *ts as u64)
+                };
+                days.insert(
+                    day,
+                    DayStars {
+                        part_1: star("1"),
+                        part_2: star("2"),
+                    },
+                );
+            }
+        }
+
+        Ok(Member {
+            id,
+            name,
+            local_score: number("local_score")?,
+            stars: number("stars")?,
+            days,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Leaderboard, SortBy, format_duration};
+    use crate::day;
+
+    // 2025-12-01 05:00:00 UTC is the unlock of day 1.
+    const JSON: &str = r#"{
+        "event": "2025",
+        "owner_id": 1,
+        "members": {
+            "1": {
+                "id": 1, "name": "Alice", "local_score": 10, "stars": 2, "global_score": 0,
+                "completion_day_level": {
+                    "1": { "1": { "get_star_ts": 1764565500 }, "2": { "get_star_ts": 1764566100 } }
+                }
+            },
+            "2": {
+                "id": 2, "name": null, "local_score": 12, "stars": 1, "global_score": 0,
+                "completion_day_level": {
+                    "2": { "1": { "get_star_ts": 1764651610 } }
+                }
+            }
+        }
+    }"#;
+
+    #[test]
+    fn parses_members_and_stars() {
+        let leaderboard = Leaderboard::try_from(JSON.to_string()).unwrap();
+        assert_eq!(leaderboard.year, 2025);
+        assert_eq!(leaderboard.members[0].name, "(anonymous user #2)");
+
+        let alice = &leaderboard.members[1];
+        assert_eq!(alice.days[&day!(1)].part_1, Some(1_764_565_500));
+        assert_eq!(alice.days[&day!(1)].part_2, Some(1_764_566_100));
+    }
+
+    #[test]
+    fn validates_days_against_year_of_leaderboard() {
+        let json = |year: &str| {
+            JSON.replace("\"2025\"", &format!("\"{year}\""))
+                .replace("\"2\": { \"1\"", "\"20\": { \"1\"")
+        };
+
+        let leaderboard = Leaderboard::try_from(json("2024")).unwrap();
+        assert!(leaderboard.members[0].days.contains_key(&day!(20)));
+        assert_eq!(
+            Leaderboard::try_from(json("2025")).unwrap_err(),
+            "invalid day `20`."
+        );
+    }
+
+    #[test]
+    fn sorts_by_stars() {
+        let mut leaderboard = Leaderboard::try_from(JSON.to_string()).unwrap();
+        leaderboard.sort(SortBy::Stars);
+        assert_eq!(leaderboard.members[0].name, "Alice");
+    }
+
+    #[test]
+    fn renders_times_relative_to_unlock() {
+        let leaderboard = Leaderboard::try_from(JSON.to_string()).unwrap();
+        let rendered = leaderboard.render();
+        assert!(rendered.contains(
+            "Alice                   00:05:00     00:15:00     00:10:00
+"
+        ));
+        assert!(rendered.contains(
+            "(anonymous user #2)     00:00:10            -            -
+"
+        ));
+    }
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(format_duration(None), "-");
+        assert_eq!(format_duration(Some(3661)), "01:01:01");
+        assert_eq!(format_duration(Some(90061)), "1d 01:01:01");
+    }
+}
@@ -0,0 +1,349 @@
+//! Converts the HTML of a puzzle page into markdown.
+//! Only the subset of HTML used by the Advent of Code website is supported.
+
+/// Converts a puzzle page to markdown, keeping the puzzle descriptions and the
+/// "Your puzzle answer was" paragraphs in document order.
+#[must_use]
+pub fn from_puzzle_html(html: &str) -> String {
+    let main = match (html.find("<main>"), html.find("</main>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+
+    let mut blocks = String::new();
+    let mut rest = main;
+
+    loop {
+        let article = rest.find("<article");
+        let answer = rest.find("<p>Your puzzle answer was");
+
+        let (start, closing) = match (article, answer) {
+            (Some(a), Some(b)) if b < a => (b, "</p>"),
+            (Some(a), _) => (a, "</article>"),
+            (None, Some(b)) => (b, "</p>"),
+            (None, None) => break,
+        };
+
+        let end = rest[start..]
+            .find(closing)
+            .map_or(rest.len(), |i| start + i + closing.len());
+
+        blocks.push_str(&rest[start..end]);
+        rest = &rest[end..];
+    }
+
+    from_html(&blocks)
+}
+
+/// Converts an HTML fragment to markdown.
+#[must_use]
+pub fn from_html(html: &str) -> String {
+    let mut out = String::new();
+    let mut links: Vec<Option<String>> = vec![];
+    let mut in_pre = false;
+    let mut skip_depth = 0;
+
+    for token in tokenize(html) {
+        match token {
+            Token::Start(name, attrs) => {
+                if skip_depth > 0 || SKIPPED.contains(&name.as_str()) {
+                    skip_depth += 1;
+                    continue;
+                }
+
+                match name.as_str() {
+                    "h2" => {
+                        blank_line(&mut out);
+                        out.push_str("## ");
+                    }
+                    "p" => blank_line(&mut out),
+                    "pre" => {
+                        blank_line(&mut out);
+                        out.push_str("```\n");
+                        in_pre = true;
+                    }
+                    "ul" | "ol" => blank_line(&mut out),
+                    "li" => {
+                        new_line(&mut out);
+                        out.push_str("- ");
+                    }
+                    "br" => out.push('\n'),
+                    "em" if !in_pre => out.push('*'),
+                    "code" if !in_pre => out.push('`'),
+                    "a" => {
+                        let href = attribute(&attrs, "href");
+                        if href.is_some() {
+                            out.push('[');
+                        }
+                        links.push(href);
+                    }
+                    _ => {}
+                }
+            }
+            Token::End(name) => {
+                if skip_depth > 0 {
+                    skip_depth -= 1;
+                    continue;
+                }
+
+                match name.as_str() {
+                    "h2" | "p" | "ul" | "ol" => blank_line(&mut out),
+                    "pre" => {
+                        new_line(&mut out);
+                        out.push_str("```");
+                        blank_line(&mut out);
+                        in_pre = false;
+                    }
+                    "em" if !in_pre => out.push('*'),
+                    "code" if !in_pre => out.push('`'),
+                    "a" => {
+                        if let Some(Some(href)) = links.pop() {
+                            out.push_str(&format!("]({href})"));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Token::Text(text) => {
+                if skip_depth > 0 {
+                    continue;
+                }
+
+                if in_pre {
+                    out.push_str(&text);
+                } else {
+                    push_collapsed(&mut out, &text);
+                }
+            }
+        }
+    }
+
+    let mut md = out.trim().to_string();
+    md.push('\n');
+    md
+}
+
+/// Elements whose content never ends up in the markdown.
+const SKIPPED: [&str; 4] = ["script", "style", "form", "noscript"];
+
+/// Elements that never have a closing tag.
+const VOID: [&str; 6] = ["br", "img", "input", "meta", "link", "hr"];
+
+#[derive(Debug, PartialEq, Eq)]
+enum Token {
+    Start(String, Vec<(String, String)>),
+    End(String),
+    Text(String),
+}
+
+fn tokenize(html: &str) -> Vec<Token> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(open) = rest.find('<') else {
+            tokens.push(Token::Text(decode_entities(rest)));
+            break;
+        };
+
+        if open > 0 {
+            tokens.push(Token::Text(decode_entities(&rest[..open])));
+        }
+
+        rest = &rest[open..];
+
+        if rest.starts_with("<!--") {
+            rest = rest.find("-->").map_or("", |i| &rest[i + 3..]);
+            continue;
+        }
+
+        let Some(close) = rest.find('>') else {
+            tokens.push(Token::Text(decode_entities(rest)));
+            break;
+        };
+
+        let tag = &rest[1..close];
+        rest = &rest[close + 1..];
+
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::End(name.trim().to_ascii_lowercase()));
+        } else if !tag.starts_with('!') {
+            let self_closing = tag.ends_with('/');
+            let tag = tag.trim_end_matches('/');
+            let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+            let name = name.to_ascii_lowercase();
+            let is_void = self_closing || VOID.contains(&name.as_str());
+
+            tokens.push(Token::Start(name.clone(), parse_attributes(attrs)));
+            if is_void {
+                tokens.push(Token::End(name));
+            }
+        }
+    }
+
+    tokens
+}
+
+fn parse_attributes(s: &str) -> Vec<(String, String)> {
+    let mut attrs = vec![];
+    let mut rest = s.trim();
+
+    while !rest.is_empty() {
+        let name_end = rest
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let name = rest[..name_end].to_ascii_lowercase();
+        rest = rest[name_end..].trim_start();
+
+        let mut value = String::new();
+        if let Some(after_eq) = rest.strip_prefix('=') {
+            let after_eq = after_eq.trim_start();
+            let quote = after_eq.chars().next().filter(|c| *c == '"' || *c == '\'');
+
+            if let Some(quote) = quote {
+                let end = after_eq[1..].find(quote).map_or(after_eq.len(), |i| i + 1);
+                value = decode_entities(&after_eq[1..end]);
+                rest = after_eq.get(end + 1..).unwrap_or("").trim_start();
+            } else {
+                let end = after_eq.find(char::is_whitespace).unwrap_or(after_eq.len());
+                value = decode_entities(&after_eq[..end]);
+                rest = after_eq[end..].trim_start();
+            }
+        }
+
+        attrs.push((name, value));
+    }
+
+    attrs
+}
+
+fn attribute(attrs: &[(String, String)], name: &str) -> Option<String> {
+    attrs
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.clone())
+}
+
+fn decode_entities(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+
+        let decoded = rest.find(';').filter(|end| *end <= 10).and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "amp" => Some('&'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                _ => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                    .and_then(char::from_u32),
+            }?;
+            Some((c, end))
+        });
+
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/// Appends text while collapsing runs of whitespace into single spaces.
+fn push_collapsed(out: &mut String, text: &str) {
+    for (i, word) in text.split_whitespace().enumerate() {
+        let at_line_start = out.is_empty() || out.ends_with('\n');
+        let needs_space = i > 0 || text.starts_with(char::is_whitespace);
+        if needs_space && !at_line_start && !out.ends_with(' ') {
+            out.push(' ');
+        }
+        out.push_str(word);
+    }
+
+    if text.ends_with(char::is_whitespace) && !text.trim().is_empty() {
+        out.push(' ');
+    }
+}
+
+fn trim_trailing_spaces(out: &mut String) {
+    while out.ends_with(' ') {
+        out.pop();
+    }
+}
+
+fn new_line(out: &mut String) {
+    trim_trailing_spaces(out);
+    if !out.is_empty() && !out.ends_with('\n') {
+        out.push('\n');
+    }
+}
+
+fn blank_line(out: &mut String) {
+    new_line(out);
+    if !out.is_empty() && !out.ends_with("\n\n") {
+        out.push('\n');
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{from_html, from_puzzle_html};
+
+    #[test]
+    fn converts_inline_elements() {
+        let md = from_html(
+            "<p>The answer is <code><em>42</em></code>, see <a href=\"/2025\">[calendar]</a>.</p>",
+        );
+        assert_eq!(md, "The answer is `*42*`, see [[calendar]](/2025).\n");
+    }
+
+    #[test]
+    fn keeps_code_blocks_verbatim() {
+        let md = from_html(
+            "<p>For example:</p>\n<pre><code>1 &lt; 2\n<em>3</em> &amp; 4\n</code></pre>\n<p>Done.</p>",
+        );
+        assert_eq!(md, "For example:\n\n```\n1 < 2\n3 & 4\n```\n\nDone.\n");
+    }
+
+    #[test]
+    fn converts_headings_and_lists() {
+        let md = from_html("<h2>--- Day 1: Test ---</h2><ul>\n<li>one</li>\n<li>two</li>\n</ul>");
+        assert_eq!(md, "## --- Day 1: Test ---\n\n- one\n- two\n");
+    }
+
+    #[test]
+    fn extracts_puzzle_articles() {
+        let html = r#"<html><body><header>nav</header><main>
+<article class="day-desc"><h2>--- Day 1: Test ---</h2><p>Part one.</p></article>
+<p>Your puzzle answer was <code>12</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Part two.</p></article>
+<form method="post" action="1/answer"><input type="text" name="answer"/></form>
+<p>You can also share this.</p>
+</main></body></html>"#;
+
+        assert_eq!(
+            from_puzzle_html(html),
+            "## --- Day 1: Test ---\n\nPart one.\n\nYour puzzle answer was `12`.\n\n## --- Part Two ---\n\nPart two.\n"
+        );
+    }
+}
@@ -1,68 +1,278 @@
-use std::{env, fs};
+use std::{borrow::Cow, env};
 
 pub mod aoc_cli;
+pub mod aoc_client;
+pub mod clock;
 pub mod commands;
+pub mod config;
+#[cfg(feature = "embed-inputs")]
+pub mod embedded;
+pub mod encryption;
+pub mod http_cache;
+pub mod leaderboard;
+pub mod markdown;
+pub mod ocr;
+pub mod params;
+pub mod parse;
+pub mod puzzle;
+pub mod render;
 pub mod runner;
+pub mod session;
 
 pub use day::*;
+pub use layout::{BinPuzzle, Layout, Puzzle};
 
+mod answers;
 mod day;
+mod diff;
+mod examples;
+mod inputs;
+mod integrity;
+mod journal;
+mod layout;
+#[cfg(feature = "test_lib")]
+mod mock_server;
 mod readme_benchmarks;
+mod readme_stars;
 mod run_multi;
+mod shape;
+#[cfg(feature = "test_lib")]
+mod temp_dir;
+mod templates;
 mod timings;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// Helper function that reads a text file to a string, decrypting it if only its `.enc` version exists.
+/// The text is [normalized](normalize), use [`read_file_raw`] where whitespace matters.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, puzzle: impl Into<Puzzle>) -> String {
+    normalize(&read_file_raw(folder, puzzle))
+}
+
+/// Helper function that reads a text file to a string as is.
+#[must_use]
+pub fn read_file_raw(folder: &str, puzzle: impl Into<Puzzle>) -> String {
+    let puzzle = puzzle.into();
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
+    let filepath = cwd
+        .join(puzzle.data_dir())
+        .join(folder)
+        .join(format!("{}.txt", puzzle.day));
+    let f = encryption::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
+/// The text is [normalized](normalize).
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, puzzle: impl Into<Puzzle>, part: u8) -> String {
+    let puzzle = puzzle.into();
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
-        .join("data")
+        .join(puzzle.data_dir())
         .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+        .join(format!("{}-{part}.txt", puzzle.day));
+    let f = encryption::read_to_string(filepath);
+    normalize(&f.expect("could not open input file"))
+}
+
+/// Reads the puzzle input of a day, the file passed to the solution with `--input <path>`, or the
+/// n-th example with `--example <n>`.
+/// Exits early if the input is obviously not a puzzle input, e.g. an error page.
+/// The input is [normalized](normalize), use [`read_input_raw`] where whitespace matters.
+#[must_use]
+pub fn read_input(puzzle: impl Into<Puzzle>) -> String {
+    normalize(&read_input_raw(puzzle))
+}
+
+/// Reads the puzzle input of a day like [`read_input`], but as is.
+#[must_use]
+pub fn read_input_raw(puzzle: impl Into<Puzzle>) -> String {
+    let puzzle = puzzle.into();
+    let named_path = named_input_path(puzzle);
+
+    let input = match &named_path {
+        Some(path) => encryption::read_to_string(path).expect("could not open input file"),
+        None => read_file_raw("inputs", puzzle),
+    };
+
+    integrity::check_input(puzzle, &input, named_path.is_none());
+    input
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Reads the puzzle input of a day like [`read_input_raw`], but as bytes.
+#[must_use]
+pub fn read_input_bytes(puzzle: impl Into<Puzzle>) -> Cow<'static, [u8]> {
+    Cow::Owned(read_input_raw(puzzle).into_bytes())
+}
+
+/// Returns the path of the input that [`read_input`] reads.
+#[must_use]
+pub fn input_path(puzzle: impl Into<Puzzle>) -> String {
+    let puzzle = puzzle.into();
+    named_input_path(puzzle).unwrap_or_else(|| puzzle.input_path())
+}
+
+/// Returns the file passed to the solution with `--input <path>`, or the example passed with
+/// `--example <n>`.
+fn named_input_path(puzzle: Puzzle) -> Option<String> {
+    if let Some(n) = params::example_arg() {
+        return Some(puzzle.example_path(n));
+    }
+
+    let mut args = env::args().skip_while(|arg| arg != "--input");
+    args.next()?;
+    Some(args.next().expect("expected a path after `--input`"))
+}
+
+/// Normalizes text for parsers that expect plain LF formatting: removes a byte order mark and
+/// carriage returns, and ends non-empty text with exactly one newline.
+#[must_use]
+pub fn normalize(text: &str) -> String {
+    let text = text
+        .strip_prefix('\u{feff}')
+        .unwrap_or(text)
+        .replace('\r', "");
+    let text = text.trim_end_matches('\n');
+
+    if text.is_empty() {
+        String::new()
+    } else {
+        format!("{text}\n")
+    }
+}
+
+/// Creates the constants `DAY` and `PUZZLE` and sets up the input and runner for each part.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// Pass `raw` instead to receive the input without [normalization](crate::template::normalize),
+/// or `bytes` to receive it as is as a `&[u8]`.
+///
+/// Puzzle [parameters](crate::template::params) are declared with their values for the real
+/// input, e.g. `solution!(8, params { connections: usize = 1000 })`. This creates a `Params`
+/// struct, which each part receives after the input.
+///
+/// With the `embed-inputs` feature, the input is embedded into the binary at compile time instead
+/// of being read at startup.
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+        $crate::solution!(@impl $day, read_input, plain, [part_one, 1] [part_two, 2]);
+    };
+    ($day:expr, params { $($params:tt)* }) => {
+        $crate::solution!(@params $($params)*);
+        $crate::solution!(@impl $day, read_input, params, [part_one, 1] [part_two, 2]);
+    };
+    ($day:expr, raw, params { $($params:tt)* }) => {
+        $crate::solution!(@params $($params)*);
+        $crate::solution!(@impl $day, read_input_raw, params, [part_one, 1] [part_two, 2]);
+    };
+    ($day:expr, bytes, params { $($params:tt)* }) => {
+        $crate::solution!(@params $($params)*);
+        $crate::solution!(@impl $day, read_input_bytes, params, [part_one, 1] [part_two, 2]);
     };
     ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+        $crate::solution!(@impl $day, read_input, plain, [part_one, 1]);
     };
     ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+        $crate::solution!(@impl $day, read_input, plain, [part_two, 2]);
     };
+    ($day:expr, raw) => {
+        $crate::solution!(@impl $day, read_input_raw, plain, [part_one, 1] [part_two, 2]);
+    };
+    ($day:expr, bytes) => {
+        $crate::solution!(@impl $day, read_input_bytes, plain, [part_one, 1] [part_two, 2]);
+    };
+
+    (@params $($name:ident: $type:ty = $value:expr),* $(,)?) => {
+        /// Parameters that differ between the examples and the real input.
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct Params {
+            $(pub $name: $type,)*
+        }
+
+        impl Params {
+            /// The parameters of the real input.
+            pub fn input() -> Self {
+                Self { $($name: $value,)* }
+            }
+
+            /// The parameters of the n-th example, as overridden in its manifest.
+            pub fn example(n: usize) -> Self {
+                #[allow(unused_mut)]
+                let mut params = Self::input();
+                for (name, value) in $crate::template::params::example(PUZZLE, n) {
+                    match name.as_str() {
+                        $(stringify!($name) => {
+                            params.$name = $crate::template::params::parse(&name, &value);
+                        })*
+                        _ => panic!("unknown parameter `{name}` in the example manifest of day {DAY}"),
+                    }
+                }
+                params
+            }
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+            /// The parameters of the input passed to the solution.
+            fn from_args() -> Self {
+                $crate::template::params::example_arg().map_or_else(Self::input, Self::example)
+            }
+        }
+    };
+
+    (@run params, $func:expr, $input:expr, $part:expr) => {
+        let params = Params::from_args();
+        run_part(|input| $func(input, &params), $input, PUZZLE, $part);
+    };
+    (@run plain, $func:expr, $input:expr, $part:expr) => {
+        run_part($func, $input, PUZZLE, $part);
+    };
+
+    (@impl $day:expr, $read:ident, $mode:ident, $( [$func:expr, $part:expr] )*) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
+        /// The puzzle of this solution, i.e. the current day in its year.
+        const PUZZLE: $crate::template::BinPuzzle =
+            $crate::template::BinPuzzle::new(env!("CARGO_BIN_NAME"), DAY);
+
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            #[cfg(not(feature = "embed-inputs"))]
+            let input = $crate::template::$read(PUZZLE);
+            #[cfg(feature = "embed-inputs")]
+            let input = $crate::template::embedded::$read(
+                PUZZLE,
+                include!(concat!(env!("OUT_DIR"), "/inputs/", env!("CARGO_BIN_NAME"), ".rs")),
+            );
+            let input = &*input;
+            $( $crate::solution!(@run $mode, $func, input, $part); )*
         }
     };
 }
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::normalize;
+
+    #[test]
+    fn normalizes_line_endings() {
+        assert_eq!(normalize("\u{feff}1\r\n2\r\n\r\n3\r\n"), "1\n2\n\n3\n");
+    }
+
+    #[test]
+    fn ends_with_single_newline() {
+        assert_eq!(normalize("1\n2"), "1\n2\n");
+        assert_eq!(normalize("1\n2 \n\n\n"), "1\n2 \n");
+        assert_eq!(normalize("  1\n"), "  1\n");
+        assert_eq!(normalize("\r\n"), "");
+    }
+}
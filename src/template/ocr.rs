@@ -0,0 +1,241 @@
+//! Reads the block letters some puzzles draw as their answer, e.g.
+//!
+//! ```text
+//! #..#.###.
+//! #..#..#..
+//! ####..#..
+//! #..#..#..
+//! #..#..#..
+//! #..#.###.
+//! ```
+//!
+//! Both fonts of advent of code are recognized: letters 6 cells high, used by most puzzles, and
+//! letters 10 cells high. Letters are separated by blank columns. Lit cells are `#` or `█`, every
+//! other character is blank.
+
+/// Letters 4 cells wide and 6 cells high.
+const FONT_6: [(char, &str); 18] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', ".###\n..#.\n..#.\n..#.\n..#.\n.###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+/// Letters 6 cells wide and 10 cells high.
+const FONT_10: [(char, &str); 15] = [
+    (
+        'A',
+        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'B',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+    ),
+    (
+        'C',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+    ),
+    (
+        'E',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'F',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'G',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+    ),
+    (
+        'H',
+        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'J',
+        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+    ),
+    (
+        'K',
+        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+    ),
+    (
+        'L',
+        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'N',
+        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+    ),
+    (
+        'P',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'R',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+    ),
+    (
+        'X',
+        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+    ),
+    (
+        'Z',
+        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+    ),
+];
+
+/// Recognizes the letters drawn by a multi-line string.
+/// Returns `None` if the text is not made of known letters.
+#[must_use]
+pub fn recognize(art: &str) -> Option<String> {
+    let grid: Vec<Vec<bool>> = art
+        .lines()
+        .map(|line| line.chars().map(|c| c == '#' || c == '█').collect())
+        .collect();
+
+    recognize_grid(&grid)
+}
+
+/// Recognizes the letters drawn by the lit cells of a grid, given as rows.
+/// Returns `None` if the grid is not made of known letters.
+#[must_use]
+pub fn recognize_grid<R: AsRef<[bool]>>(rows: &[R]) -> Option<String> {
+    let rows: Vec<&[bool]> = rows.iter().map(AsRef::as_ref).collect();
+    let is_blank = |row: &&[bool]| !row.contains(&true);
+
+    let top = rows.iter().position(|row| !is_blank(row))?;
+    let bottom = rows.iter().rposition(|row| !is_blank(row))?;
+    let rows = &rows[top..=bottom];
+
+    let font: &[(char, &str)] = match rows.len() {
+        6 => &FONT_6,
+        10 => &FONT_10,
+        _ => return None,
+    };
+
+    let width = rows.iter().map(|row| row.len()).max()?;
+    let is_lit = |x: usize| rows.iter().any(|row| row.get(x) == Some(&true));
+
+    let mut letters = String::new();
+    let mut x = 0;
+
+    while x < width {
+        if !is_lit(x) {
+            x += 1;
+            continue;
+        }
+
+        let start = x;
+        while x < width && is_lit(x) {
+            x += 1;
+        }
+
+        let glyph = glyph(rows, start..x);
+        let (letter, _) = font
+            .iter()
+            .find(|(_, pattern)| trim_columns(pattern) == glyph)?;
+        letters.push(*letter);
+    }
+
+    Some(letters)
+}
+
+/// Draws the columns of a letter with `#` and `.`.
+fn glyph(rows: &[&[bool]], columns: std::ops::Range<usize>) -> String {
+    rows.iter()
+        .map(|row| {
+            columns
+                .clone()
+                .map(|x| if row.get(x) == Some(&true) { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Removes the blank columns around a letter of a font.
+fn trim_columns(pattern: &str) -> String {
+    let grid: Vec<Vec<bool>> = pattern
+        .lines()
+        .map(|line| line.chars().map(|c| c == '#').collect())
+        .collect();
+    let rows: Vec<&[bool]> = grid.iter().map(Vec::as_slice).collect();
+    let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    let is_lit = |x: usize| rows.iter().any(|row| row[x]);
+
+    let start = (0..width).find(|x| is_lit(*x)).unwrap_or(0);
+    let end = (0..width).rfind(|x| is_lit(*x)).map_or(0, |x| x + 1);
+
+    glyph(&rows, start..end)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{recognize, recognize_grid};
+
+    #[test]
+    fn recognizes_small_letters() {
+        let art = "
+#..#.###..####.
+#..#..#...#....
+####..#...###..
+#..#..#...#....
+#..#..#...#....
+#..#.###..####.
+";
+        assert_eq!(recognize(art), Some("HIE".into()));
+        assert_eq!(
+            recognize(&art.replace('#', "█").replace('.', " ")),
+            Some("HIE".into())
+        );
+    }
+
+    #[test]
+    fn recognizes_large_letters() {
+        let art = "\
+#....#..######
+#....#.......#
+#....#.......#
+#....#......#.
+######.....#..
+#....#....#...
+#....#...#....
+#....#..#.....
+#....#..#.....
+#....#..######";
+        assert_eq!(recognize(art), Some("HZ".into()));
+    }
+
+    #[test]
+    fn recognizes_grids() {
+        let grid: Vec<Vec<bool>> = ".##.\n#..#\n#..#\n####\n#..#\n#..#"
+            .lines()
+            .map(|line| line.chars().map(|c| c == '#').collect())
+            .collect();
+        assert_eq!(recognize_grid(&grid), Some("A".into()));
+    }
+
+    #[test]
+    fn rejects_unknown_art() {
+        assert_eq!(recognize("#.#\n.#.\n#.#"), None);
+        assert_eq!(recognize("###.\n#..#\n#..#\n#..#\n#..#\n###."), None);
+        assert_eq!(recognize(""), None);
+    }
+}
@@ -0,0 +1,114 @@
+//! Puzzle parameters that differ between the examples and the real input, e.g. the number of
+//! steps to simulate.
+//!
+//! Parameters and their values for the real input are declared in the `solution!` macro:
+//! `solution!(8, params { connections: usize = 1000 })`. Examples override them in the manifest
+//! `data/examples/NN.toml`, with one table per example number:
+//!
+//! ```toml
+//! [1]
+//! connections = 10
+//! ```
+use std::{collections::BTreeMap, env, fmt::Display, io, str::FromStr};
+
+use crate::template::{Puzzle, encryption};
+
+/// Returns the parameters the manifest sets for the n-th (1-based) example of a puzzle.
+/// A missing manifest sets no parameters.
+#[must_use]
+pub fn example(puzzle: impl Into<Puzzle>, n: usize) -> BTreeMap<String, String> {
+    let path = puzzle.into().example_manifest_path();
+
+    let manifest = match encryption::read_to_string(&path) {
+        Ok(manifest) => manifest,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return BTreeMap::new(),
+        Err(e) => panic!("could not read example manifest \"{path}\": {e}"),
+    };
+
+    parse_manifest(&manifest, n)
+        .unwrap_or_else(|e| panic!("invalid example manifest \"{path}\": {e}"))
+}
+
+fn parse_manifest(manifest: &str, n: usize) -> Result<BTreeMap<String, String>, String> {
+    let mut examples: BTreeMap<String, BTreeMap<String, toml::Value>> =
+        toml::from_str(manifest).map_err(|e| e.to_string())?;
+
+    examples
+        .remove(&n.to_string())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(name, value)| {
+            let value = match value {
+                toml::Value::String(s) => s,
+                toml::Value::Integer(i) => i.to_string(),
+                toml::Value::Float(f) => f.to_string(),
+                toml::Value::Boolean(b) => b.to_string(),
+                _ => {
+                    return Err(format!(
+                        "parameter `{name}` is not a string, number or boolean"
+                    ));
+                }
+            };
+            Ok((name, value))
+        })
+        .collect()
+}
+
+/// Parses the value of a parameter.
+///
+/// # Panics
+/// If the value does not parse into the type of the parameter.
+#[must_use]
+pub fn parse<T: FromStr>(name: &str, value: &str) -> T
+where
+    T::Err: Display,
+{
+    value
+        .parse()
+        .unwrap_or_else(|e| panic!("invalid value \"{value}\" of parameter `{name}`: {e}"))
+}
+
+/// Returns the example a solution runs on, as passed with `--example <n>`.
+#[must_use]
+pub fn example_arg() -> Option<usize> {
+    let mut args = env::args().skip_while(|arg| arg != "--example");
+    args.next()?;
+    let n = args.next().and_then(|n| n.parse().ok()).filter(|n| *n > 0);
+    Some(n.expect("expected an example number after `--example`"))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse, parse_manifest};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn reads_overrides_of_example() {
+        let manifest = "[1]\nconnections = 10\nname = \"abc\"\n\n[2]\nconnections = 5\n";
+
+        assert_eq!(
+            parse_manifest(manifest, 1).unwrap(),
+            BTreeMap::from([
+                ("connections".to_string(), "10".to_string()),
+                ("name".to_string(), "abc".to_string())
+            ])
+        );
+        assert_eq!(parse_manifest(manifest, 2).unwrap()["connections"], "5");
+        assert!(parse_manifest(manifest, 3).unwrap().is_empty());
+        assert!(parse_manifest("connections = 10", 1).is_err());
+        assert!(parse_manifest("[1]\nconnections = [10]", 1).is_err());
+    }
+
+    #[test]
+    fn parses_values() {
+        assert_eq!(parse::<usize>("connections", "10"), 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid value \"ten\" of parameter `connections`")]
+    fn rejects_invalid_values() {
+        let _ = parse::<usize>("connections", "ten");
+    }
+}
@@ -0,0 +1,179 @@
+//! Errors of puzzle input parsers that point at the offending text.
+//!
+//! Parsers wrap their input in a [`Source`] and pass it slices of that input, e.g. a line or a
+//! number within it. Errors then carry the line, column and text they refer to, and the runner
+//! prints them as a caret diagnostic against the input file:
+//!
+//! ```text
+//! Part 1: ⚠ line 2, column 3: invalid digit found in string
+//!  --> data/inputs/09.txt:2:3
+//!   |
+//! 2 | 7,1x
+//!   |   ^^
+//! ```
+use std::{error::Error, fmt::Display, str::FromStr};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    /// One-based line of the offending text.
+    pub line: usize,
+    /// One-based column of the offending text, in characters.
+    pub column: usize,
+    pub text: String,
+    /// The full line the offending text is on.
+    pub line_text: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+impl ParseError {
+    /// Renders the error as a diagnostic that underlines the offending text in its line.
+    #[must_use]
+    pub fn diagnostic(&self, path: &str) -> String {
+        let gutter = " ".repeat(self.line.to_string().len());
+        let offset = " ".repeat(self.column - 1);
+        // text spanning several lines is underlined up to the end of its first line.
+        let width = self
+            .text
+            .lines()
+            .next()
+            .map_or(0, |line| line.chars().count())
+            .max(1);
+
+        format!(
+            "{gutter}--> {path}:{}:{}\n{gutter} |\n{} | {}\n{gutter} | {offset}{}",
+            self.line,
+            self.column,
+            self.line,
+            self.line_text,
+            "^".repeat(width)
+        )
+    }
+}
+
+/// The input of a parser, used to locate the slices of it that fail to parse.
+#[derive(Clone, Copy, Debug)]
+pub struct Source<'a> {
+    input: &'a str,
+}
+
+impl<'a> Source<'a> {
+    #[must_use]
+    pub fn new(input: &'a str) -> Self {
+        Self { input }
+    }
+
+    /// Returns an error about `text`, which should be a slice of the input.
+    /// Other text is searched for in the input, and reported at the end of the input if absent.
+    #[must_use]
+    pub fn error(&self, text: &str, message: impl Display) -> ParseError {
+        let offset = self.offset_of(text);
+        let before = &self.input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = self.input[offset..]
+            .find('\n')
+            .map_or(self.input.len(), |i| offset + i);
+
+        ParseError {
+            message: message.to_string(),
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: text.to_string(),
+            line_text: self.input[line_start..line_end].to_string(),
+        }
+    }
+
+    /// Parses `text`, a slice of the input, with [`FromStr`].
+    pub fn parse<T: FromStr>(&self, text: &str) -> Result<T, ParseError>
+    where
+        T::Err: Display,
+    {
+        text.parse().map_err(|e| self.error(text, e))
+    }
+
+    /// Splits `text`, a slice of the input, at the first occurrence of `delimiter`.
+    pub fn split_once(
+        &self,
+        text: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        text.split_once(delimiter)
+            .ok_or_else(|| self.error(text, format!("expected {delimiter:?}")))
+    }
+
+    fn offset_of(&self, text: &str) -> usize {
+        let start = self.input.as_ptr() as usize;
+        let address = text.as_ptr() as usize;
+
+        if (start..=start + self.input.len()).contains(&address) {
+            address - start
+        } else {
+            self.input
+                .find(text)
+                .filter(|_| !text.is_empty())
+                .unwrap_or(self.input.len())
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Source;
+
+    #[test]
+    fn locates_slices_of_input() {
+        let input = "1,2\n7,1x\n";
+        let source = Source::new(input);
+        let number = input.lines().nth(1).unwrap().split(',').nth(1).unwrap();
+
+        let error = source.parse::<u64>(number).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.text, "1x");
+        assert_eq!(error.line_text, "7,1x");
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn counts_columns_in_characters() {
+        let input = "äö:x";
+        let error = Source::new(input).parse::<u64>(&input[5..]).unwrap_err();
+        assert_eq!(error.column, 4);
+    }
+
+    #[test]
+    fn reports_missing_text_at_end() {
+        let source = Source::new("1\n2");
+        let error = source.error("", "expected a second section");
+        assert_eq!((error.line, error.column), (2, 2));
+
+        let error = source.split_once("1\n2", "\n\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.message, "expected \"\\n\\n\"");
+    }
+
+    #[test]
+    fn renders_diagnostic() {
+        let input = "1,2\n7,1x\n";
+        let error = Source::new(input).parse::<u64>(&input[6..8]).unwrap_err();
+        assert_eq!(
+            error.diagnostic("data/inputs/09.txt"),
+            " --> data/inputs/09.txt:2:3\n  |\n2 | 7,1x\n  |   ^^"
+        );
+    }
+}
@@ -0,0 +1,135 @@
+//! Helpers for puzzle descriptions stored as markdown in `data/puzzles`.
+use std::path::Path;
+
+use crate::template::Puzzle;
+
+const PART_TWO_HEADING: &str = "## --- Part Two ---";
+
+/// Returns the title of a puzzle, e.g. `Secret Entrance` for `## --- Day 1: Secret Entrance ---`.
+#[must_use]
+pub fn title(md: &str) -> Option<&str> {
+    let heading = md.lines().next()?.strip_prefix("## --- Day ")?;
+    let (_, title) = heading.strip_suffix(" ---")?.split_once(": ")?;
+    Some(title)
+}
+
+/// Returns the section of a puzzle description that belongs to one part.
+/// The part one section includes the title, the part two section starts at its heading.
+#[must_use]
+pub fn part_section(md: &str, part: u8) -> Option<&str> {
+    let part_two_start = md.find(PART_TWO_HEADING);
+
+    match (part, part_two_start) {
+        (1, Some(start)) => Some(md[..start].trim_end()),
+        (1, None) => Some(md.trim_end()),
+        (2, Some(start)) => Some(md[start..].trim_end()),
+        _ => None,
+    }
+}
+
+/// Returns the answer of the example of one part, i.e. the last emphasized code span of its
+/// section (e.g. `` `*40*` ``). Paragraphs with our own accepted answers are skipped.
+#[must_use]
+pub fn example_answer(md: &str, part: u8) -> Option<String> {
+    let section = part_section(md, part)?;
+
+    section
+        .lines()
+        .filter(|line| !line.starts_with("Your puzzle answer was"))
+        .flat_map(|line| line.split('`').skip(1).step_by(2))
+        .filter_map(|code| code.strip_prefix('*')?.strip_suffix('*'))
+        .filter(|answer| !answer.is_empty())
+        .last()
+        .map(str::to_string)
+}
+
+/// Returns our accepted answers listed in a puzzle description, i.e. the
+/// "Your puzzle answer was" paragraphs of part one and part two.
+#[must_use]
+pub fn accepted_answers(md: &str) -> [Option<String>; 2] {
+    let mut answers = md.lines().filter_map(|line| {
+        let rest = line.strip_prefix("Your puzzle answer was `")?;
+        rest.split('`').next().map(str::to_string)
+    });
+
+    [answers.next(), answers.next()]
+}
+
+/// Returns the path of the snapshot that keeps the description as it was before part two was revealed.
+#[must_use]
+pub fn get_part_one_snapshot_path(puzzle: Puzzle) -> String {
+    Path::new(&puzzle.puzzle_path())
+        .with_extension("part1.md")
+        .to_string_lossy()
+        .to_string()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        accepted_answers, example_answer, get_part_one_snapshot_path, part_section, title,
+    };
+    use crate::day;
+    use crate::template::Puzzle;
+
+    const PUZZLE: &str = "## --- Day 1: Test ---\n\nOne.\n\n## --- Part Two ---\n\nTwo.\n";
+
+    #[test]
+    fn splits_parts() {
+        assert_eq!(
+            part_section(PUZZLE, 1),
+            Some("## --- Day 1: Test ---\n\nOne.")
+        );
+        assert_eq!(part_section(PUZZLE, 2), Some("## --- Part Two ---\n\nTwo."));
+    }
+
+    #[test]
+    fn handles_missing_part_two() {
+        let puzzle = "## --- Day 1: Test ---\n\nOne.\n";
+        assert_eq!(
+            part_section(puzzle, 1),
+            Some("## --- Day 1: Test ---\n\nOne.")
+        );
+        assert_eq!(part_section(puzzle, 2), None);
+    }
+
+    #[test]
+    fn finds_example_answers() {
+        let puzzle = "## --- Day 8: Test ---
+
+Connect the `*10*` closest pairs, then multiply the sizes to get `*40*`.
+
+Your puzzle answer was `123`.
+
+## --- Part Two ---
+
+Continue until all are connected. This results in `*25272*`.
+
+Your puzzle answer was `*456*`.
+";
+        assert_eq!(example_answer(puzzle, 1), Some("40".to_string()));
+        assert_eq!(example_answer(puzzle, 2), Some("25272".to_string()));
+        assert_eq!(example_answer("Nothing here.", 1), None);
+    }
+
+    #[test]
+    fn finds_accepted_answers() {
+        let puzzle = "One.\n\nYour puzzle answer was `12`.\n\n## --- Part Two ---\n\nTwo.\n";
+        assert_eq!(accepted_answers(puzzle), [Some("12".to_string()), None]);
+    }
+
+    #[test]
+    fn finds_title() {
+        assert_eq!(title(PUZZLE), Some("Test"));
+        assert_eq!(title("## --- Part Two ---\n"), None);
+    }
+
+    #[test]
+    fn replaces_only_extension_in_snapshot_path() {
+        let path = get_part_one_snapshot_path(Puzzle::new(2025, day!(1)));
+        assert!(path.ends_with("01.part1.md"));
+        assert_eq!(path.matches(".md").count(), 1);
+    }
+}
@@ -1,11 +1,14 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
+/// In the year-qualified layout, every year has its own section.
 use std::{fs, io};
 
-use crate::template::Day;
+use crate::template::Puzzle;
+use crate::template::config;
+use crate::template::layout::{self, Layout, SOLUTIONS_DIR};
 use crate::template::timings::Timings;
 
-static MARKER: &str = "<!--- benchmarking table --->";
+pub static MARKER: &str = "<!--- benchmarking table --->";
 
 #[allow(dead_code)]
 #[derive(Debug)]
@@ -21,17 +24,13 @@ impl From<std::io::Error> for Error {
 }
 
 pub struct TablePosition {
-    pos_start: usize,
-    pos_end: usize,
+    pub pos_start: usize,
+    pub pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/// Locates the section between two occurrences of `marker` (or a single marker) in the readme.
+pub fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -52,11 +51,66 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+/// Returns the marker of the section of a year, e.g. `<!--- benchmarking table 2025 --->`.
+/// Without a year, the single section of the flat layout is used.
+#[must_use]
+pub fn section_marker(marker: &str, year: Option<u16>) -> String {
+    match year {
+        Some(year) => {
+            let (name, end) = split_marker(marker);
+            format!("{name} {year}{end}")
+        }
+        None => marker.into(),
+    }
+}
+
+/// Splits a marker into its name and the end of the comment, e.g. `<!--- benchmarking table` and ` --->`.
+fn split_marker(marker: &str) -> (&str, &str) {
+    let name = marker.trim_end_matches(['-', '>', ' ']);
+    marker.split_at(name.len())
+}
+
+/// Like [`locate_table`], but adds a missing year section in front of the sections of other years.
+pub fn locate_section(
+    readme: &mut String,
+    marker: &str,
+    year: Option<u16>,
+) -> Result<TablePosition, Error> {
+    let section = section_marker(marker, year);
+
+    if !readme.contains(&section)
+        && let Some(pos) = readme.find(split_marker(marker).0)
+    {
+        readme.insert_str(pos, &format!("{section}\n\n"));
+    }
+
+    locate_table(readme, &section)
+}
+
+/// Returns the year of the readme section to update, which is only set in the year-qualified layout.
+#[must_use]
+pub fn section_year() -> Option<u16> {
+    match Layout::detect() {
+        Layout::Flat => None,
+        Layout::YearQualified => Some(layout::active_year()),
+    }
+}
+
+fn construct_table(
+    prefix: &str,
+    marker: &str,
+    timings: Timings,
+    total_millis: f64,
+    year: Option<u16>,
+) -> String {
+    let marker = section_marker(marker, year);
+    let header = match year {
+        Some(year) => format!("{prefix} {year} Benchmarks"),
+        None => format!("{prefix} Benchmarks"),
+    };
 
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
+        marker.clone(),
         header,
         String::new(),
         "| Day | Part 1 | Part 2 |".into(),
@@ -64,7 +118,11 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
     ];
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
+        let path = match year {
+            Some(year) => Layout::YearQualified.bin_name(Puzzle::new(year, timing.day)),
+            None => Layout::Flat.bin_name(Puzzle::current(timing.day)),
+        };
+        let path = format!("./{SOLUTIONS_DIR}/{path}.rs");
         lines.push(format!(
             "| [Day {}]({}) | `{}` | `{}` |",
             timing.day.into_inner(),
@@ -76,30 +134,43 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(marker);
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+fn update_content(
+    s: &mut String,
+    marker: &str,
+    timings: Timings,
+    total_millis: f64,
+    year: Option<u16>,
+) -> Result<(), Error> {
+    let positions = locate_section(s, marker, year)?;
+    let table = construct_table("##", marker, timings, total_millis, year);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
 pub fn update(timings: Timings) -> Result<(), Error> {
-    let path = "README.md";
+    let readme_config = &config::get().readme;
+    let path = &readme_config.path;
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(
+        &mut readme,
+        &readme_config.benchmarks_marker,
+        timings,
+        total_millis,
+        section_year(),
+    )?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{MARKER, update_content};
+    use super::{MARKER, section_marker, update_content};
     use crate::{day, template::timings::Timing, template::timings::Timings};
 
     fn get_mock_timings() -> Timings {
@@ -109,18 +180,24 @@ mod tests {
                     day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    part_1_error: None,
+                    part_2_error: None,
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    part_1_error: None,
+                    part_2_error: None,
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
+                    part_1_error: None,
+                    part_2_error: None,
                     total_nanos: 9e+10,
                 },
             ],
@@ -131,28 +208,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, MARKER, get_mock_timings(), 190.0, None).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, MARKER, get_mock_timings(), 190.0, None).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, MARKER, get_mock_timings(), 190.0, None).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, MARKER, get_mock_timings(), 190.0, None).unwrap();
+        update_content(&mut s, MARKER, get_mock_timings(), 190.0, None).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,7 +237,7 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, MARKER, get_mock_timings(), 190.0, None).unwrap();
         let expected = [
             "foo",
             "bar",
@@ -180,4 +257,28 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn adds_year_sections() {
+        let mut s = format!("foo\n{}\n{}\nbaz", MARKER, MARKER);
+        let year_2024 = section_marker(MARKER, Some(2024));
+        s = s.replace(MARKER, &year_2024);
+
+        update_content(&mut s, MARKER, get_mock_timings(), 190.0, Some(2025)).unwrap();
+        update_content(&mut s, MARKER, get_mock_timings(), 190.0, Some(2025)).unwrap();
+
+        assert_eq!(s.matches("<!--- benchmarking table 2025 --->").count(), 2);
+        assert_eq!(s.matches(&year_2024).count(), 2);
+        assert!(s.find("## 2025 Benchmarks").unwrap() < s.find(&year_2024).unwrap());
+        assert!(s.contains("| [Day 2](./src/bin/2025-02.rs) | `30ms` | `40ms` |"));
+    }
+
+    #[test]
+    fn qualifies_custom_markers() {
+        assert_eq!(
+            section_marker("<!-- timings -->", Some(2025)),
+            "<!-- timings 2025 -->"
+        );
+        assert_eq!(section_marker("<!-- timings -->", None), "<!-- timings -->");
+    }
 }
@@ -0,0 +1,149 @@
+/// Module that updates the readme with star progress, computed from the accepted answers.
+/// Replaces the section written by the `advent-readme-stars` action, using the same marker.
+/// In the year-qualified layout, every year has its own section.
+use std::fs;
+
+use crate::template::answers::{Answer, Answers};
+use crate::template::readme_benchmarks::{Error, locate_section, section_marker, section_year};
+use crate::template::{config, days_in_active_year, days_in_year, layout};
+
+pub static MARKER: &str = "<!--- advent_readme_stars table --->";
+
+fn construct_table(
+    prefix: &str,
+    marker: &str,
+    answers: &Answers,
+    year: Option<u16>,
+    section: Option<u16>,
+) -> String {
+    let marker = section_marker(marker, section);
+    let header = match year {
+        Some(year) => format!("{prefix} {year} Results"),
+        None => format!("{prefix} Results"),
+    };
+
+    let mut lines: Vec<String> = vec![
+        marker.clone(),
+        header,
+        String::new(),
+        "| Day | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: |".into(),
+    ];
+
+    let star = |answer: &Option<String>| if answer.is_some() { "⭐" } else { " " };
+
+    let last = year.map_or_else(days_in_active_year, days_in_year);
+
+    let answers: Vec<&Answer> = answers
+        .data
+        .iter()
+        .filter(|answer| answer.day <= last)
+        .collect();
+    let stars = answers
+        .iter()
+        .flat_map(|answer| [&answer.part_1, &answer.part_2])
+        .filter(|part| part.is_some())
+        .count();
+
+    for answer in answers {
+        let day = answer.day.into_inner();
+        let link = match year {
+            Some(year) => format!("[Day {day}](https://adventofcode.com/{year}/day/{day})"),
+            None => format!("Day {day}"),
+        };
+        lines.push(format!(
+            "| {link} | {} | {} |",
+            star(&answer.part_1),
+            star(&answer.part_2)
+        ));
+    }
+
+    lines.push(String::new());
+    lines.push(format!("**Total: {stars} / {} ⭐**", 2 * u16::from(last)));
+    lines.push(marker);
+
+    lines.join("\n")
+}
+
+fn update_content(
+    s: &mut String,
+    marker: &str,
+    answers: &Answers,
+    year: Option<u16>,
+    section: Option<u16>,
+) -> Result<(), Error> {
+    let positions = locate_section(s, marker, section)?;
+    let table = construct_table("##", marker, answers, year, section);
+    s.replace_range(positions.pos_start..positions.pos_end, &table);
+    Ok(())
+}
+
+pub fn update(answers: &Answers) -> Result<(), Error> {
+    let readme_config = &config::get().readme;
+    let path = &readme_config.path;
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    update_content(
+        &mut readme,
+        &readme_config.stars_marker,
+        answers,
+        Some(layout::active_year()),
+        section_year(),
+    )?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{MARKER, update_content};
+    use crate::day;
+    use crate::template::answers::{Answer, Answers};
+
+    fn get_mock_answers() -> Answers {
+        Answers {
+            data: vec![
+                Answer {
+                    day: day!(1),
+                    part_1: Some("1".into()),
+                    part_2: Some("2".into()),
+                },
+                Answer {
+                    day: day!(3),
+                    part_1: Some("3".into()),
+                    part_2: None,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn replaces_single_marker() {
+        let mut s = format!("foo\n{MARKER}\nbar");
+        update_content(&mut s, MARKER, &get_mock_answers(), Some(2025), None).unwrap();
+        update_content(&mut s, MARKER, &get_mock_answers(), Some(2025), None).unwrap();
+        assert_eq!(s.matches(MARKER).count(), 2);
+        assert_eq!(s.matches("## 2025 Results").count(), 1);
+    }
+
+    #[test]
+    fn format_stars() {
+        let mut s = format!("foo\n{MARKER}\nbar");
+        update_content(&mut s, MARKER, &get_mock_answers(), Some(2025), None).unwrap();
+        let expected = [
+            "foo",
+            MARKER,
+            "## 2025 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2025/day/1) | ⭐ | ⭐ |",
+            "| [Day 3](https://adventofcode.com/2025/day/3) | ⭐ |   |",
+            "",
+            "**Total: 3 / 24 ⭐**",
+            MARKER,
+            "bar",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+}
@@ -0,0 +1,239 @@
+//! Renders puzzle descriptions stored as markdown for the terminal.
+use std::env;
+
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+
+const DEFAULT_WIDTH: usize = 80;
+
+/// Returns the width to wrap text at, read from `COLUMNS` if set.
+#[must_use]
+pub fn terminal_width() -> usize {
+    env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.parse().ok())
+        .unwrap_or(DEFAULT_WIDTH)
+        .max(20)
+}
+
+/// Renders markdown as produced by [`crate::template::markdown::from_html`]:
+///  1. paragraphs and list items are word-wrapped at `width`.
+///  2. headings and emphasized text (e.g. answers) are printed in bold.
+///  3. code blocks are drawn inside a box and never wrapped.
+#[must_use]
+pub fn render(md: &str, width: usize) -> String {
+    let mut out: Vec<String> = vec![];
+    let mut lines = md.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        if line.starts_with("```") {
+            let mut code = vec![];
+            for line in lines.by_ref() {
+                if line.starts_with("```") {
+                    break;
+                }
+                code.push(line);
+            }
+            out.extend(render_code_block(&code));
+        } else if let Some(heading) = line.strip_prefix("## ") {
+            out.push(format!("{ANSI_BOLD}{}{ANSI_RESET}", plain(heading)));
+        } else if let Some(item) = line.strip_prefix("- ") {
+            out.extend(wrap(
+                &styled_chars(item),
+                width.saturating_sub(4),
+                "  • ",
+                "    ",
+            ));
+        } else if line.trim().is_empty() {
+            out.push(String::new());
+        } else {
+            // join the lines of a paragraph before wrapping them.
+            let mut paragraph = line.to_string();
+            while let Some(next) = lines.peek() {
+                if next.trim().is_empty() || next.starts_with("```") || next.starts_with("## ") {
+                    break;
+                }
+                paragraph.push(' ');
+                paragraph.push_str(next);
+                lines.next();
+            }
+            out.extend(wrap(&styled_chars(&paragraph), width, "", ""));
+        }
+    }
+
+    let mut rendered = out.join("\n");
+    rendered.push('\n');
+    rendered
+}
+
+fn render_code_block(code: &[&str]) -> Vec<String> {
+    let inner_width = code
+        .iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let mut lines = vec![format!("┌{}┐", "─".repeat(inner_width + 2))];
+    for line in code {
+        let padding = inner_width - line.chars().count();
+        lines.push(format!("│ {line}{} │", " ".repeat(padding)));
+    }
+    lines.push(format!("└{}┘", "─".repeat(inner_width + 2)));
+    lines
+}
+
+/// Strips inline markup, keeping only the text.
+fn plain(s: &str) -> String {
+    styled_chars(s).into_iter().map(|(c, _)| c).collect()
+}
+
+/// Resolves inline markup into characters flagged as bold or not.
+/// Supports `*emphasis*`, `` `code` ``, `` `*emphasized code*` `` and `[links](href)`.
+fn styled_chars(s: &str) -> Vec<(char, bool)> {
+    let chars: Vec<char> = s.chars().collect();
+    let mut out = vec![];
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '`' => {
+                if let Some(len) = chars[i + 1..].iter().position(|c| *c == '`') {
+                    let span = &chars[i + 1..i + 1 + len];
+                    let emphasized =
+                        span.len() > 2 && span[0] == '*' && span[span.len() - 1] == '*';
+                    if emphasized {
+                        out.extend(span[1..span.len() - 1].iter().map(|c| (*c, true)));
+                    } else {
+                        out.extend(span.iter().map(|c| (*c, false)));
+                    }
+                    i += len + 2;
+                    continue;
+                }
+            }
+            '*' => {
+                if let Some(len) = chars[i + 1..].iter().position(|c| *c == '*')
+                    && len > 0
+                {
+                    let span: String = chars[i + 1..i + 1 + len].iter().collect();
+                    out.extend(styled_chars(&span).into_iter().map(|(c, _)| (c, true)));
+                    i += len + 2;
+                    continue;
+                }
+            }
+            '[' => {
+                let close = chars[i + 1..].iter().position(|c| *c == ']');
+                if let Some(close) = close
+                    && chars.get(i + close + 2) == Some(&'(')
+                    && let Some(end) = chars[i + close + 2..].iter().position(|c| *c == ')')
+                {
+                    let text: String = chars[i + 1..i + 1 + close].iter().collect();
+                    out.extend(styled_chars(&text));
+                    i += close + 2 + end + 1;
+                    continue;
+                }
+            }
+            _ => {}
+        }
+
+        out.push((chars[i], false));
+        i += 1;
+    }
+
+    out
+}
+
+/// Wraps styled text at `width` visible characters.
+fn wrap(chars: &[(char, bool)], width: usize, first_indent: &str, indent: &str) -> Vec<String> {
+    let words: Vec<&[(char, bool)]> = chars
+        .split(|(c, _)| c.is_whitespace())
+        .filter(|word| !word.is_empty())
+        .collect();
+
+    let mut lines = vec![];
+    let mut line: Vec<(char, bool)> = vec![];
+
+    for word in words {
+        if !line.is_empty() && line.len() + 1 + word.len() > width {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push((' ', false));
+        }
+        line.extend_from_slice(word);
+    }
+
+    if !line.is_empty() {
+        lines.push(line);
+    }
+
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let prefix = if i == 0 { first_indent } else { indent };
+            format!("{prefix}{}", to_ansi(line))
+        })
+        .collect()
+}
+
+fn to_ansi(chars: &[(char, bool)]) -> String {
+    let mut out = String::new();
+    let mut bold = false;
+
+    for &(c, is_bold) in chars {
+        if is_bold != bold {
+            out.push_str(if is_bold { ANSI_BOLD } else { ANSI_RESET });
+            bold = is_bold;
+        }
+        out.push(c);
+    }
+
+    if bold {
+        out.push_str(ANSI_RESET);
+    }
+
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::render;
+    use crate::template::{ANSI_BOLD, ANSI_RESET};
+
+    #[test]
+    fn wraps_paragraphs() {
+        let rendered = render("one two three\nfour five\n", 9);
+        assert_eq!(rendered, "one two\nthree\nfour five\n");
+    }
+
+    #[test]
+    fn highlights_answers() {
+        let rendered = render("The answer is `*42*`, see [this](/2025).", 80);
+        assert_eq!(
+            rendered,
+            format!("The answer is {ANSI_BOLD}42{ANSI_RESET}, see this.\n")
+        );
+    }
+
+    #[test]
+    fn keeps_literal_stars_in_code() {
+        let rendered = render("Multiply with `*` here.", 80);
+        assert_eq!(rendered, "Multiply with * here.\n");
+    }
+
+    #[test]
+    fn boxes_code_blocks() {
+        let rendered = render("```\n12\n3\n```\n", 80);
+        assert_eq!(rendered, "┌────┐\n│ 12 │\n│ 3  │\n└────┘\n");
+    }
+
+    #[test]
+    fn renders_headings_and_lists() {
+        let rendered = render("## --- Day 1 ---\n\n- one\n- two", 80);
+        assert_eq!(
+            rendered,
+            format!("{ANSI_BOLD}--- Day 1 ---{ANSI_RESET}\n\n  • one\n  • two\n")
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use std::{collections::HashSet, io};
+use std::{collections::HashSet, fmt::Display, io};
 
 use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
 
@@ -9,6 +9,7 @@ use super::{
 
 pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+    let mut errors: Vec<(Day, usize, String)> = vec![];
 
     let mut need_space = false;
 
@@ -24,16 +25,36 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
             println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
             println!("------");
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+            let output = match child_commands::run_solution(day, is_timed, is_release, None) {
+                Ok(output) => output,
+                Err(e) => {
+                    eprintln!("Could not run solution: {e}");
+                    return;
+                }
+            };
 
             if output.is_empty() {
                 println!("Not solved.");
             } else {
                 let val = child_commands::parse_exec_time(&output, day);
+
+                for (part, error) in [(1, &val.part_1_error), (2, &val.part_2_error)] {
+                    if let Some(message) = error {
+                        errors.push((day, part, message.clone()));
+                    }
+                }
+
                 timings.push(val);
             }
         });
 
+    if !errors.is_empty() {
+        println!("\n{ANSI_BOLD}Errors:{ANSI_RESET}");
+        for (day, part, message) in &errors {
+            println!("  Day {day} part {part}: {message}");
+        }
+    }
+
     if is_timed {
         let timings = Timings { data: timings };
         let total_millis = timings.total_millis();
@@ -46,56 +67,74 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
     }
 }
 
-#[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
     BrokenPipe,
     IO(io::Error),
 }
 
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::BrokenPipe => write!(f, "could not read the output of the solution"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
 impl From<std::io::Error> for Error {
     fn from(e: std::io::Error) -> Self {
         Error::IO(e)
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 /// All solutions live in isolated binaries.
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
 pub mod child_commands {
-    use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use super::Error;
+    use crate::template::{ANSI_BOLD, ANSI_RESET, Day, Puzzle};
     use std::{
+        fmt::Display,
         io::{BufRead, BufReader},
         path::Path,
         process::{Command, Stdio},
         thread,
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    /// Run the solution bin for a given day of the active year, optionally with another input file than `data/inputs/NN.txt`.
+    pub fn run_solution(
+        day: Day,
+        is_timed: bool,
+        is_release: bool,
+        input: Option<&Path>,
+    ) -> Result<Vec<String>, Error> {
+        let puzzle = Puzzle::current(day);
+
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
+        if !Path::new(&puzzle.module_path()).exists() {
             return Ok(vec![]);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let bin_name = puzzle.bin_name();
+        let mut args = vec!["run", "--quiet", "--bin", &bin_name];
 
         if is_release {
             args.push("--release");
         }
 
+        args.push("--");
+
         if is_timed {
             // mirror `--time` flag to child invocations.
-            args.push("--");
             args.push("--time");
         }
 
+        let input = input.map(|path| path.to_string_lossy());
+        if let Some(input) = &input {
+            args.push("--input");
+            args.push(input);
+        }
+
         // spawn child command with piped stdout/stderr.
         // forward output to stdout/stderr while grabbing stdout lines.
 
@@ -128,11 +167,14 @@ pub mod child_commands {
         Ok(output)
     }
 
+    /// Parses the timings of a day's solution, and the errors its parts failed with.
     pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
         let mut timings = super::Timing {
             day,
             part_1: None,
             part_2: None,
+            part_1_error: None,
+            part_2_error: None,
             total_nanos: 0_f64,
         };
 
@@ -161,9 +203,87 @@ pub mod child_commands {
                 timings.total_nanos += nanos;
             });
 
+        let [part_1, part_2] = parse_results(output);
+        timings.part_1_error = part_1.error().map(str::to_string);
+        timings.part_2_error = part_2.error().map(str::to_string);
+
         timings
     }
 
+    /// The result of a part, as printed by a solution.
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    pub enum PartOutput {
+        Answer(String),
+        /// The message of the error a part failed with.
+        Error(String),
+        /// The part is unsolved, or its result spans several lines without drawing known letters.
+        #[default]
+        Missing,
+    }
+
+    impl PartOutput {
+        #[must_use]
+        pub fn answer(&self) -> Option<&str> {
+            match self {
+                PartOutput::Answer(answer) => Some(answer),
+                _ => None,
+            }
+        }
+
+        #[must_use]
+        pub fn error(&self) -> Option<&str> {
+            match self {
+                PartOutput::Error(message) => Some(message),
+                _ => None,
+            }
+        }
+    }
+
+    impl Display for PartOutput {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                PartOutput::Answer(answer) => write!(f, "{answer}"),
+                PartOutput::Error(message) => write!(f, "error \"{message}\""),
+                PartOutput::Missing => write!(f, "nothing"),
+            }
+        }
+    }
+
+    /// Parses the results printed for both parts.
+    pub fn parse_results(output: &[String]) -> [PartOutput; 2] {
+        let mut results = [PartOutput::Missing, PartOutput::Missing];
+
+        for line in output {
+            // intermediate results are overwritten with a carriage return.
+            let line = line.rsplit('\r').next().unwrap_or_default();
+
+            let Some((part, rest)) = line.split_once(": ") else {
+                continue;
+            };
+            let index = match part {
+                "Part 1" => 0,
+                "Part 2" => 1,
+                _ => continue,
+            };
+
+            let (rest, is_error) = match rest.strip_prefix("⚠ ") {
+                Some(rest) => (rest, true),
+                None => (rest, false),
+            };
+
+            results[index] = match rest
+                .strip_prefix(ANSI_BOLD)
+                .and_then(|rest| rest.split_once(ANSI_RESET))
+            {
+                Some((message, _)) if is_error => PartOutput::Error(message.to_string()),
+                Some((answer, _)) => PartOutput::Answer(answer.to_string()),
+                None => PartOutput::Missing,
+            };
+        }
+
+        results
+    }
+
     fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
         s.split(postfix).next()?.parse().ok()
     }
@@ -205,9 +325,10 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::{PartOutput, parse_exec_time, parse_results};
 
         use crate::day;
+        use crate::template::{ANSI_BOLD, ANSI_RESET};
 
         #[test]
         fn parses_execution_times() {
@@ -239,6 +360,51 @@ pub mod child_commands {
             assert_eq!(res.part_2.unwrap(), "100ms");
         }
 
+        #[test]
+        fn parses_results() {
+            let res = parse_results(&[
+                format!(
+                    "Part 1: {ANSI_BOLD}42{ANSI_RESET}\rPart 1: {ANSI_BOLD}42{ANSI_RESET} (1.0ms)"
+                ),
+                "Part 2: ✖\rPart 2: ✖             ".into(),
+            ]);
+            assert_eq!(res, [PartOutput::Answer("42".into()), PartOutput::Missing]);
+        }
+
+        #[test]
+        fn parses_errors() {
+            let res = parse_results(&[
+                format!(
+                    "Part 1: ⚠\rPart 1: ⚠ {ANSI_BOLD}invalid digit found in string{ANSI_RESET}"
+                ),
+                "  caused by: line 3".into(),
+                "Part 2: ▼ (1.0ms)".into(),
+            ]);
+            assert_eq!(
+                res,
+                [
+                    PartOutput::Error("invalid digit found in string".into()),
+                    PartOutput::Missing
+                ]
+            );
+        }
+
+        #[test]
+        fn parses_errors_with_timings() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
+                    format!("Part 2: ⚠\rPart 2: ⚠ {ANSI_BOLD}bad input{ANSI_RESET}"),
+                    "".into(),
+                ],
+                day!(1),
+            );
+            assert_eq!(res.part_1.unwrap(), "74.13ns");
+            assert_eq!(res.part_1_error, None);
+            assert_eq!(res.part_2, None);
+            assert_eq!(res.part_2_error.unwrap(), "bad input");
+        }
+
         #[test]
         fn parses_missing_parts() {
             let res = parse_exec_time(
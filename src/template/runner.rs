@@ -1,35 +1,95 @@
 /// Encapsulates code that interacts with solution functions.
+use std::error::Error;
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{Write, stdout};
-use std::process::Output;
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime};
 use std::{cmp, env, process};
 
 use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::answers::Answers;
+use crate::template::aoc_client::{self, Backend, Verdict};
+use crate::template::commands::refresh;
+use crate::template::journal::{Event, Journal};
+use crate::template::ocr;
+use crate::template::params;
+use crate::template::parse::ParseError;
+use crate::template::{ANSI_ITALIC, ANSI_RESET, Puzzle, aoc_cli, config, input_path};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// The outcome of running a solution part.
+#[derive(Debug)]
+pub enum Outcome<T> {
+    Solved(T),
+    /// The part is not implemented yet, or has no answer.
+    Unsolved,
+    Failed(Box<dyn Error>),
+}
+
+/// Return types of solution parts: `Option<T>`, where `None` means the part is not solved yet,
+/// or `Result<T, E>`, where an error is reported together with its chain of causes.
+pub trait PartResult {
+    type Answer: Display;
+
+    fn into_outcome(self) -> Outcome<Self::Answer>;
+}
+
+impl<T: Display> PartResult for Option<T> {
+    type Answer = T;
+
+    fn into_outcome(self) -> Outcome<T> {
+        self.map_or(Outcome::Unsolved, Outcome::Solved)
+    }
+}
+
+impl<T: Display, E: Into<Box<dyn Error>>> PartResult for Result<T, E> {
+    type Answer = T;
+
+    fn into_outcome(self) -> Outcome<T> {
+        match self {
+            Ok(result) => Outcome::Solved(result),
+            Err(e) => Outcome::Failed(e.into()),
+        }
+    }
+}
+
+pub fn run_part<I: Copy, R: PartResult>(
+    func: impl Fn(I) -> R,
+    input: I,
+    puzzle: impl Into<Puzzle>,
+    part: u8,
+) {
+    let puzzle = puzzle.into();
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, duration, samples) = run_timed(
+        |input| func(input).into_outcome(),
+        input,
+        |result| print_result(result, &part_str, ""),
+    );
 
     print_result(&result, &part_str, &format_duration(&duration, samples));
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    if let Outcome::Failed(e) = &result
+        && let Some(e) = e.downcast_ref::<ParseError>()
+    {
+        println!("{}", e.diagnostic(&input_path(puzzle)));
+    }
+
+    if let Outcome::Solved(result) = result {
+        submit_result(answer(&result), puzzle, part);
     }
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///
+/// Failed parts are never benched.
 fn run_timed<I: Copy, T>(
-    func: impl Fn(I) -> T,
+    func: impl Fn(I) -> Outcome<T>,
     input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+    hook: impl Fn(&Outcome<T>),
+) -> (Outcome<T>, Duration, u128) {
     let timer = Instant::now();
     let result = {
         #[cfg(feature = "dhat-heap")]
@@ -41,7 +101,7 @@ fn run_timed<I: Copy, T>(
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
+    let run = if std::env::args().any(|x| x == "--time") && !matches!(result, Outcome::Failed(_)) {
         bench(func, input, &base_time)
     } else {
         (base_time, 1)
@@ -56,8 +116,9 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    let policy = &config::get().benchmarks;
+    let bench_iterations = (policy.target().as_nanos() / cmp::max(base_time.as_nanos(), 10))
+        .clamp(policy.min_samples, policy.max_samples);
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -90,13 +151,20 @@ fn format_duration(duration: &Duration, samples: u128) -> String {
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_result<T: Display>(result: &Outcome<T>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
-        Some(result) => {
-            if result.to_string().contains('\n') {
-                let str = format!("{part}: ▼ {duration_str}");
+        Outcome::Solved(result) => {
+            let result = result.to_string();
+            if result.contains('\n') {
+                // ASCII art is printed below its letters, if they are recognized.
+                let str = match ocr::recognize(&result) {
+                    Some(letters) => {
+                        format!("{part}: {ANSI_BOLD}{letters}{ANSI_RESET}{duration_str}")
+                    }
+                    None => format!("{part}: ▼ {duration_str}"),
+                };
                 if is_intermediate_result {
                     print!("{str}");
                 } else {
@@ -114,7 +182,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 }
             }
         }
-        None => {
+        Outcome::Unsolved => {
             if is_intermediate_result {
                 print!("{part}: ✖");
             } else {
@@ -122,21 +190,50 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 println!("{part}: ✖             ");
             }
         }
+        Outcome::Failed(e) => {
+            if is_intermediate_result {
+                print!("{part}: ⚠");
+            } else {
+                let (message, causes) = error_chain(e.as_ref());
+                print!("\r");
+                println!("{part}: ⚠ {ANSI_BOLD}{message}{ANSI_RESET}");
+                for cause in causes {
+                    println!("  caused by: {cause}");
+                }
+            }
+        }
+    }
+}
+
+/// Returns the message of an error and the messages of its causes, outermost first.
+fn error_chain(e: &dyn Error) -> (String, Vec<String>) {
+    let mut causes = vec![];
+    let mut source = e.source();
+    while let Some(cause) = source {
+        causes.push(cause.to_string());
+        source = cause.source();
+    }
+    (e.to_string(), causes)
+}
+
+/// Returns the answer to submit for a result: the letters of ASCII art, the result itself otherwise.
+fn answer(result: &impl Display) -> String {
+    let result = result.to_string();
+    if result.contains('\n') {
+        ocr::recognize(&result).unwrap_or(result)
+    } else {
+        result
     }
 }
 
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+///  2. a session cookie is configured or aoc-cli is installed.
+fn submit_result<T: Display>(result: T, puzzle: Puzzle, part: u8) {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
-        return None;
+        return;
     }
 
     if args.len() < 3 {
@@ -152,16 +249,111 @@ fn submit_result<T: Display>(
     };
 
     if part_submit != part {
-        return None;
+        return;
+    }
+
+    if params::example_arg().is_some() {
+        eprintln!("Results of examples are not submitted.");
+        return;
+    }
+
+    match aoc_client::backend() {
+        Ok(Backend::Native(client)) => {
+            println!("Submitting result...");
+            match client.submit(puzzle, part, &result.to_string()) {
+                Ok(submission) => {
+                    println!("{submission}");
+                    if submission.verdict == Verdict::Correct {
+                        if let Err(e) = Answers::record(puzzle, part, &result.to_string()) {
+                            eprintln!("failed to record answer: {e}");
+                        }
+                        if let Err(e) =
+                            Journal::record(puzzle, Event::Solved(part), SystemTime::now())
+                        {
+                            eprintln!("failed to record solve time: {e}");
+                        }
+                    }
+                    // part two is only revealed once part one is accepted.
+                    if submission.verdict == Verdict::Correct
+                        && part == 1
+                        && let Err(e) = refresh::refresh(&client, puzzle)
+                    {
+                        eprintln!("failed to refresh puzzle: {e}");
+                    }
+                }
+                Err(e) => {
+                    eprintln!("failed to submit result: {e}");
+                    process::exit(1);
+                }
+            }
+        }
+        Ok(Backend::AocCli) => {
+            println!("Submitting result via aoc-cli...");
+            if let Err(e) = aoc_cli::submit(puzzle, part, &result.to_string()) {
+                eprintln!("failed to call aoc-cli: {e}");
+                process::exit(1);
+            }
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Outcome, PartResult, answer, error_chain};
+    use std::{fmt::Display, io};
+
+    #[derive(Debug)]
+    struct ParseError(io::Error);
+
+    impl Display for ParseError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "could not parse line 3")
+        }
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+    impl std::error::Error for ParseError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    #[test]
+    fn converts_part_results() {
+        assert!(matches!(Some(42).into_outcome(), Outcome::Solved(42)));
+        assert!(matches!(None::<u64>.into_outcome(), Outcome::Unsolved));
+        assert!(matches!(
+            Ok::<_, String>(42).into_outcome(),
+            Outcome::Solved(42)
+        ));
+        assert!(matches!(
+            Err::<u64, _>("bad input").into_outcome(),
+            Outcome::Failed(e) if e.to_string() == "bad input"
+        ));
+    }
+
+    #[test]
+    fn collects_error_chain() {
+        let e = ParseError(io::Error::other("invalid digit"));
+        assert_eq!(
+            error_chain(&e),
+            (
+                "could not parse line 3".to_string(),
+                vec!["invalid digit".to_string()]
+            )
         );
-        process::exit(1);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    #[test]
+    fn submits_letters_of_ascii_art() {
+        let art = ".##.\n#..#\n#..#\n####\n#..#\n#..#";
+        assert_eq!(answer(&art), "A");
+        assert_eq!(answer(&"#\n#"), "#\n#");
+        assert_eq!(answer(&42), "42");
+    }
 }
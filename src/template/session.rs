@@ -0,0 +1,168 @@
+/// Locates the session cookie used to authenticate against the Advent of Code website.
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+};
+
+const SESSION_ENV_VAR: &str = "AOC_SESSION";
+const SESSION_FILE_NAME: &str = "adventofcode.session";
+
+/// Where a session cookie was found.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SessionSource {
+    Env(String),
+    ConfigFile(PathBuf),
+    HomeFile(PathBuf),
+}
+
+impl Display for SessionSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SessionSource::Env(name) => write!(f, "environment variable {name}"),
+            SessionSource::ConfigFile(path) | SessionSource::HomeFile(path) => {
+                write!(f, "\"{}\"", path.display())
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Session {
+    pub cookie: String,
+    pub source: SessionSource,
+}
+
+/// Looks up the session cookie in the following order:
+///  1. the `AOC_SESSION` environment variable.
+///  2. the config file `adventofcode.session` in `$XDG_CONFIG_HOME` (or `~/.config`).
+///  3. the file `~/.adventofcode.session`, as used by aoc-cli.
+pub struct SessionProvider {
+    env_var: String,
+    config_file: Option<PathBuf>,
+    home_file: Option<PathBuf>,
+}
+
+impl Default for SessionProvider {
+    fn default() -> Self {
+        let home = home_dir();
+
+        let config_dir = env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("APPDATA").map(PathBuf::from))
+            .or_else(|| home.as_ref().map(|home| home.join(".config")));
+
+        Self {
+            env_var: SESSION_ENV_VAR.into(),
+            config_file: config_dir.map(|dir| dir.join(SESSION_FILE_NAME)),
+            home_file: home.map(|home| home.join(format!(".{SESSION_FILE_NAME}"))),
+        }
+    }
+}
+
+impl SessionProvider {
+    pub fn new(env_var: &str, config_file: Option<PathBuf>, home_file: Option<PathBuf>) -> Self {
+        Self {
+            env_var: env_var.into(),
+            config_file,
+            home_file,
+        }
+    }
+
+    /// Returns the first non-empty session cookie, if any.
+    pub fn find(&self) -> Option<Session> {
+        if let Some(cookie) = env::var(&self.env_var).ok().and_then(clean) {
+            return Some(Session {
+                cookie,
+                source: SessionSource::Env(self.env_var.clone()),
+            });
+        }
+
+        if let Some(cookie) = self.config_file.as_deref().and_then(read_cookie) {
+            return Some(Session {
+                cookie,
+                source: SessionSource::ConfigFile(self.config_file.clone()?),
+            });
+        }
+
+        if let Some(cookie) = self.home_file.as_deref().and_then(read_cookie) {
+            return Some(Session {
+                cookie,
+                source: SessionSource::HomeFile(self.home_file.clone()?),
+            });
+        }
+
+        None
+    }
+}
+
+/// Returns the home directory of the current user.
+pub fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+}
+
+fn read_cookie(path: &Path) -> Option<String> {
+    fs::read_to_string(path).ok().and_then(clean)
+}
+
+/// Trims the cookie and strips an optional `session=` prefix copied along from the browser.
+fn clean(s: String) -> Option<String> {
+    let s = s.trim();
+    let s = s.strip_prefix("session=").unwrap_or(s);
+    (!s.is_empty()).then(|| s.to_string())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{SessionProvider, SessionSource};
+    use crate::template::temp_dir::TempDir;
+    use std::{fs, path::PathBuf};
+
+    fn temp_file(dir: &TempDir, name: &str, content: &str) -> PathBuf {
+        let path = dir.path().join(name);
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn prefers_config_file_over_home_file() {
+        let dir = TempDir::new("session");
+        let config = temp_file(&dir, "config", "session=abc\n");
+        let home = temp_file(&dir, "home", "def");
+        let provider =
+            SessionProvider::new("AOC_SESSION_TEST_UNSET", Some(config.clone()), Some(home));
+
+        let session = provider.find().unwrap();
+        assert_eq!(session.cookie, "abc");
+        assert_eq!(session.source, SessionSource::ConfigFile(config));
+    }
+
+    #[test]
+    fn skips_empty_files() {
+        let dir = TempDir::new("session");
+        let config = temp_file(&dir, "empty", "  \n");
+        let home = temp_file(&dir, "home-only", "def\n");
+        let provider =
+            SessionProvider::new("AOC_SESSION_TEST_UNSET", Some(config), Some(home.clone()));
+
+        let session = provider.find().unwrap();
+        assert_eq!(session.cookie, "def");
+        assert_eq!(session.source, SessionSource::HomeFile(home));
+    }
+
+    #[test]
+    fn returns_none_without_session() {
+        let dir = TempDir::new("session");
+        let provider = SessionProvider::new(
+            "AOC_SESSION_TEST_UNSET",
+            None,
+            Some(dir.path().join("missing")),
+        );
+        assert_eq!(provider.find(), None);
+    }
+}
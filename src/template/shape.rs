@@ -0,0 +1,524 @@
+//! Detects the shape of a puzzle input, so `cargo scaffold` can generate a matching `parse`
+//! function and `Input` type instead of the same boilerplate every day.
+//!
+//! The detection is a heuristic over all lines of the input. When nothing more specific fits,
+//! the input is parsed into its lines.
+use std::fmt::Write;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Separator {
+    Line,
+    Comma,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Item {
+    Number {
+        signed: bool,
+    },
+    /// A range of numbers like `3-5`.
+    Range,
+    Text,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Shape {
+    /// Lines of the same width, e.g. `..@@.@@@@.`.
+    Grid,
+    /// One item per line, or a single line of comma-separated items.
+    List { separator: Separator, item: Item },
+    /// Lines of the same number of comma-separated numbers, e.g. `162,817,812`.
+    Tuples { len: usize, signed: bool },
+    /// Lines like `aaa: bbb ccc`.
+    KeyValues,
+    /// Lines of bracketed tokens, e.g. `[.##.] (3) (1,3) {3,5,4,7}`.
+    Bracketed,
+    /// Two sections separated by a blank line, each with a shape of its own.
+    TwoSections(Box<Shape>, Box<Shape>),
+    /// More than two sections separated by blank lines.
+    Sections,
+}
+
+/// Returns the shape of an input.
+#[must_use]
+pub fn detect(input: &str) -> Shape {
+    let input = input.trim_end();
+    let sections: Vec<&str> = input.split("\n\n").collect();
+
+    match sections[..] {
+        [section] => detect_section(section),
+        [first, second] => Shape::TwoSections(
+            Box::new(detect_section(first)),
+            Box::new(detect_section(second)),
+        ),
+        _ => Shape::Sections,
+    }
+}
+
+fn detect_section(section: &str) -> Shape {
+    let lines: Vec<&str> = section.lines().collect();
+
+    if lines.is_empty() {
+        return Shape::List {
+            separator: Separator::Line,
+            item: Item::Text,
+        };
+    }
+
+    if let [line] = lines[..]
+        && line.contains(',')
+    {
+        let items: Vec<&str> = line.trim().split(',').collect();
+        return Shape::List {
+            separator: Separator::Comma,
+            item: detect_item(&items),
+        };
+    }
+
+    if lines.iter().all(|line| is_bracketed(line)) {
+        return Shape::Bracketed;
+    }
+
+    if lines.iter().all(|line| is_key_values(line)) {
+        return Shape::KeyValues;
+    }
+
+    let tuples: Vec<Vec<&str>> = lines.iter().map(|line| line.split(',').collect()).collect();
+    if tuples[0].len() > 1
+        && tuples.iter().all(|tuple| tuple.len() == tuples[0].len())
+        && let Item::Number { signed } = detect_item(&tuples.concat())
+    {
+        return Shape::Tuples {
+            len: tuples[0].len(),
+            signed,
+        };
+    }
+
+    if lines.len() > 1
+        && lines[0].len() > 1
+        && lines.iter().all(|line| line.len() == lines[0].len())
+        && !lines.iter().any(|line| line.contains(char::is_whitespace))
+    {
+        return Shape::Grid;
+    }
+
+    Shape::List {
+        separator: Separator::Line,
+        item: detect_item(&lines),
+    }
+}
+
+fn detect_item(items: &[&str]) -> Item {
+    if items.iter().all(|item| item.parse::<u64>().is_ok()) {
+        Item::Number { signed: false }
+    } else if items.iter().all(|item| item.parse::<i64>().is_ok()) {
+        Item::Number { signed: true }
+    } else if items.iter().all(|item| {
+        item.split_once('-')
+            .is_some_and(|(start, end)| start.parse::<u64>().is_ok() && end.parse::<u64>().is_ok())
+    }) {
+        Item::Range
+    } else {
+        Item::Text
+    }
+}
+
+fn is_bracketed(line: &str) -> bool {
+    !line.is_empty()
+        && line.split_whitespace().all(|token| {
+            matches!(
+                (token.chars().next(), token.chars().last()),
+                (Some('['), Some(']')) | (Some('('), Some(')')) | (Some('{'), Some('}'))
+            )
+        })
+}
+
+fn is_key_values(line: &str) -> bool {
+    line.split_once(": ")
+        .is_some_and(|(key, values)| !key.contains(' ') && !values.trim().is_empty())
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Returns the Rust type of a shape. Borrowed text uses the lifetime `'a`.
+fn type_of(shape: &Shape) -> String {
+    match shape {
+        Shape::Grid => "Vec<Vec<char>>".into(),
+        Shape::List { item, .. } => format!("Vec<{}>", item_type(*item)),
+        Shape::Tuples { len, signed } => format!("Vec<[{}; {len}]>", number_type(*signed)),
+        Shape::KeyValues => "Vec<(&'a str, Vec<&'a str>)>".into(),
+        Shape::Bracketed => "Vec<Vec<(char, &'a str)>>".into(),
+        Shape::TwoSections(..) => "Input".into(),
+        Shape::Sections => "Vec<Vec<&'a str>>".into(),
+    }
+}
+
+fn item_type(item: Item) -> &'static str {
+    match item {
+        Item::Number { signed } => number_type(signed),
+        Item::Range => "(u64, u64)",
+        Item::Text => "&'a str",
+    }
+}
+
+fn number_type(signed: bool) -> &'static str {
+    if signed { "i64" } else { "u64" }
+}
+
+/// Returns whether parsing a shape can fail, i.e. whether its parser reports errors to a `source`.
+fn is_fallible(shape: &Shape) -> bool {
+    !matches!(
+        shape,
+        Shape::Grid
+            | Shape::List {
+                item: Item::Text,
+                ..
+            }
+            | Shape::Sections
+    )
+}
+
+/// Returns the body of a function that parses `input` into a shape, indented by four spaces.
+/// Fallible shapes report errors to `source`, a [`Source`](crate::template::parse::Source) of the
+/// whole input.
+fn body_of(shape: &Shape) -> String {
+    match shape {
+        Shape::Grid => "    Ok(input.lines().map(|line| line.chars().collect()).collect())".into(),
+        Shape::List { separator, item } => {
+            let (iter, name) = match separator {
+                Separator::Line => (["lines()"].as_slice(), "line"),
+                Separator::Comma => (["trim()", "split(',')"].as_slice(), "item"),
+            };
+            match item {
+                Item::Number { .. } if iter.len() == 1 => format!(
+                    "    input.{}.map(|{name}| source.parse({name})).collect()",
+                    iter[0]
+                ),
+                Item::Number { .. } => format!(
+                    "    input
+        .{}
+        .map(|{name}| source.parse({name}))
+        .collect()",
+                    iter.join("\n        .")
+                ),
+                Item::Range => format!(
+                    "    input
+        .{}
+        .map(|{name}| {{
+            let (start, end) = source.split_once({name}, \"-\")?;
+            Ok((source.parse(start)?, source.parse(end)?))
+        }})
+        .collect()",
+                    iter.join("\n        .")
+                ),
+                Item::Text => format!("    Ok(input.{}.collect())", iter.join(".")),
+            }
+        }
+        Shape::Tuples { len, .. } => format!(
+            "    input
+        .lines()
+        .map(|line| {{
+            let numbers = line
+                .split(',')
+                .map(|n| source.parse(n))
+                .collect::<Result<Vec<_>, _>>()?;
+            numbers
+                .try_into()
+                .map_err(|_| source.error(line, \"expected {len} numbers\"))
+        }})
+        .collect()"
+        ),
+        Shape::KeyValues => "    input
+        .lines()
+        .map(|line| {
+            let (key, values) = source.split_once(line, \":\")?;
+            Ok((key, values.split_whitespace().collect()))
+        })
+        .collect()"
+            .into(),
+        Shape::Bracketed => "    input
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|token| {
+                    let mut chars = token.chars();
+                    match (chars.next(), chars.next_back()) {
+                        (Some(bracket), Some(_)) => Ok((bracket, chars.as_str())),
+                        _ => Err(source.error(token, \"expected a bracketed token\")),
+                    }
+                })
+                .collect()
+        })
+        .collect()"
+            .into(),
+        Shape::TwoSections(first, second) => format!(
+            "    let (first, second) = source.split_once(input, \"\\n\\n\")?;
+    Ok(Input {{
+        first: {},
+        second: {},
+    }})",
+            call("parse_first", "first", first),
+            call("parse_second", "second", second)
+        ),
+        Shape::Sections => "    Ok(input
+        .split(\"\\n\\n\")
+        .map(|section| section.lines().collect())
+        .collect())"
+            .into(),
+    }
+}
+
+/// Returns the call of the parser of a section.
+fn call(name: &str, section: &str, shape: &Shape) -> String {
+    if is_fallible(shape) {
+        format!("{name}(source, {section})?")
+    } else {
+        format!("{name}({section})?")
+    }
+}
+
+/// Returns the `Input` type and `parse` function of a shape as Rust source.
+/// Parsers return a [`ParseError`](crate::template::parse::ParseError) that points at the
+/// offending text of the input.
+#[must_use]
+pub fn parser(shape: &Shape) -> String {
+    let mut source = String::new();
+
+    if is_fallible(shape) {
+        source.push_str("use advent_of_code::template::parse::{ParseError, Source};\n\n");
+    } else {
+        source.push_str("use advent_of_code::template::parse::ParseError;\n\n");
+    }
+
+    match shape {
+        Shape::TwoSections(first, second) => {
+            let (first_type, second_type) = (type_of(first), type_of(second));
+            let lifetime = if first_type.contains("'a") || second_type.contains("'a") {
+                "<'a>"
+            } else {
+                ""
+            };
+
+            let _ = writeln!(source, "#[allow(dead_code)]");
+            let _ = writeln!(source, "struct Input{lifetime} {{");
+            let _ = writeln!(source, "    first: {first_type},");
+            let _ = writeln!(source, "    second: {second_type},");
+            let _ = writeln!(source, "}}\n");
+            source.push_str(&function(
+                "parse",
+                &format!("Input{}", elided(lifetime)),
+                shape,
+            ));
+
+            for (name, section) in [("parse_first", first), ("parse_second", second)] {
+                source.push('\n');
+                source.push_str(&section_function(name, section));
+            }
+        }
+        shape => {
+            let input_type = type_of(shape);
+            let lifetime = if input_type.contains("'a") {
+                "<'a>"
+            } else {
+                ""
+            };
+
+            let _ = writeln!(source, "type Input{lifetime} = {input_type};\n");
+            source.push_str(&function(
+                "parse",
+                &format!("Input{}", elided(lifetime)),
+                shape,
+            ));
+        }
+    }
+
+    source
+}
+
+/// Returns the function that parses a whole input, creating the `source` errors refer to.
+fn function(name: &str, return_type: &str, shape: &Shape) -> String {
+    let source = if is_fallible(shape) {
+        "    let source = Source::new(input);\n"
+    } else {
+        ""
+    };
+
+    format!(
+        "fn {name}(input: &str) -> Result<{return_type}, ParseError> {{\n{source}{}\n}}\n",
+        body_of(shape)
+    )
+}
+
+/// Returns the function that parses a section of an input. Fallible sections report errors to
+/// the `source` of the whole input, so they point at the right line.
+fn section_function(name: &str, shape: &Shape) -> String {
+    let signature = if is_fallible(shape) {
+        let signature = format!(
+            "fn {name}<'a>(source: Source<'a>, input: &'a str) -> Result<{}, ParseError>",
+            type_of(shape)
+        );
+        // long signatures are wrapped like rustfmt does.
+        if signature.len() > 98 {
+            format!(
+                "fn {name}<'a>(\n    source: Source<'a>,\n    input: &'a str,\n) -> Result<{}, ParseError>",
+                type_of(shape)
+            )
+        } else {
+            signature
+        }
+    } else {
+        format!(
+            "fn {name}(input: &str) -> Result<{}, ParseError>",
+            without_lifetime(&type_of(shape))
+        )
+    };
+
+    format!("{signature} {{\n{}\n}}\n", body_of(shape))
+}
+
+/// Turns the declared lifetime of a type into the elided one of a signature.
+fn elided(lifetime: &str) -> &'static str {
+    if lifetime.is_empty() { "" } else { "<'_>" }
+}
+
+fn without_lifetime(type_name: &str) -> String {
+    type_name.replace("&'a ", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Item, Separator, Shape, detect, parser};
+
+    #[test]
+    fn detects_grids() {
+        assert_eq!(detect("..@@.\n@@@.@\n"), Shape::Grid);
+        assert_eq!(detect("98765\n81111\n"), Shape::Grid);
+    }
+
+    #[test]
+    fn detects_lists() {
+        assert_eq!(
+            detect("L68\nL5\nR148\n"),
+            Shape::List {
+                separator: Separator::Line,
+                item: Item::Text
+            }
+        );
+        assert_eq!(
+            detect("11-22,95-115,998-1012\n"),
+            Shape::List {
+                separator: Separator::Comma,
+                item: Item::Range
+            }
+        );
+        assert_eq!(
+            detect("1\n-20\n3\n"),
+            Shape::List {
+                separator: Separator::Line,
+                item: Item::Number { signed: true }
+            }
+        );
+    }
+
+    #[test]
+    fn detects_lines_of_missing_inputs() {
+        assert_eq!(
+            detect(""),
+            Shape::List {
+                separator: Separator::Line,
+                item: Item::Text
+            }
+        );
+    }
+
+    #[test]
+    fn detects_tuples() {
+        assert_eq!(
+            detect("162,817,812\n57,618,57\n"),
+            Shape::Tuples {
+                len: 3,
+                signed: false
+            }
+        );
+    }
+
+    #[test]
+    fn detects_lines_of_tokens() {
+        assert_eq!(detect("aaa: you hhh\nyou: bbb ccc\n"), Shape::KeyValues);
+        assert_eq!(
+            detect("[.##.] (3) (1,3) {3,5,4,7}\n[...#.] (0,2) {7,5}\n"),
+            Shape::Bracketed
+        );
+    }
+
+    #[test]
+    fn detects_sections() {
+        assert_eq!(
+            detect("3-5\n10-14\n\n1\n5\n"),
+            Shape::TwoSections(
+                Box::new(Shape::List {
+                    separator: Separator::Line,
+                    item: Item::Range
+                }),
+                Box::new(Shape::List {
+                    separator: Separator::Line,
+                    item: Item::Number { signed: false }
+                })
+            )
+        );
+        assert_eq!(detect("0:\n###\n\n1:\n##.\n\n4x4: 0 0\n"), Shape::Sections);
+    }
+
+    #[test]
+    fn generates_parsers() {
+        assert_eq!(
+            parser(&detect("aaa: you hhh\n")),
+            "use advent_of_code::template::parse::{ParseError, Source};
+
+type Input<'a> = Vec<(&'a str, Vec<&'a str>)>;
+
+fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    let source = Source::new(input);
+    input
+        .lines()
+        .map(|line| {
+            let (key, values) = source.split_once(line, \":\")?;
+            Ok((key, values.split_whitespace().collect()))
+        })
+        .collect()
+}
+"
+        );
+
+        assert_eq!(
+            parser(&detect("L68\n\n1\n")),
+            "use advent_of_code::template::parse::{ParseError, Source};
+
+#[allow(dead_code)]
+struct Input<'a> {
+    first: Vec<&'a str>,
+    second: Vec<u64>,
+}
+
+fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    let source = Source::new(input);
+    let (first, second) = source.split_once(input, \"\\n\\n\")?;
+    Ok(Input {
+        first: parse_first(first)?,
+        second: parse_second(source, second)?,
+    })
+}
+
+fn parse_first(input: &str) -> Result<Vec<&str>, ParseError> {
+    Ok(input.lines().collect())
+}
+
+fn parse_second<'a>(source: Source<'a>, input: &'a str) -> Result<Vec<u64>, ParseError> {
+    input.lines().map(|line| source.parse(line)).collect()
+}
+"
+        );
+    }
+}
@@ -0,0 +1,205 @@
+//! Module templates that `cargo scaffold` creates solutions from.
+//!
+//! Besides the built-in templates, every `templates/<name>.txt` is a template named `<name>`.
+//! User templates take precedence, so a `templates/default.txt` replaces the default template.
+use std::{collections::BTreeMap, fmt::Display, fs, io, path::Path};
+
+use crate::template::Puzzle;
+
+const TEMPLATES_DIR: &str = "templates";
+
+pub const DEFAULT_TEMPLATE: &str = "default";
+
+const BUILTIN_TEMPLATES: [(&str, &str); 4] = [
+    (
+        DEFAULT_TEMPLATE,
+        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt")),
+    ),
+    (
+        "grid",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/grid.txt"
+        )),
+    ),
+    (
+        "graph",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/graph.txt"
+        )),
+    ),
+    (
+        "parse-once",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/parse-once.txt"
+        )),
+    ),
+];
+
+#[derive(Debug)]
+pub enum TemplateError {
+    Unknown {
+        name: String,
+        available: Vec<String>,
+    },
+    IO(io::Error),
+}
+
+impl Display for TemplateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TemplateError::Unknown { name, available } => write!(
+                f,
+                "unknown template \"{name}\", available templates are: {}.",
+                available.join(", ")
+            ),
+            TemplateError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for TemplateError {
+    fn from(e: io::Error) -> Self {
+        TemplateError::IO(e)
+    }
+}
+
+/// Returns the names of all templates, built-in templates first.
+#[must_use]
+pub fn names() -> Vec<String> {
+    let mut names: Vec<String> = BUILTIN_TEMPLATES
+        .iter()
+        .map(|(name, _)| (*name).to_string())
+        .collect();
+
+    let mut user: Vec<String> = fs::read_dir(TEMPLATES_DIR)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .filter_map(|path| Some(path.file_stem()?.to_string_lossy().to_string()))
+        .filter(|name| !names.contains(name))
+        .collect();
+    user.sort_unstable();
+
+    names.extend(user);
+    names
+}
+
+/// Reads a template by name.
+pub fn read(name: &str) -> Result<String, TemplateError> {
+    let path = Path::new(TEMPLATES_DIR).join(format!("{name}.txt"));
+    if path.exists() {
+        return Ok(fs::read_to_string(path)?);
+    }
+
+    BUILTIN_TEMPLATES
+        .iter()
+        .find(|(builtin, _)| *builtin == name)
+        .map(|(_, template)| (*template).to_string())
+        .ok_or_else(|| TemplateError::Unknown {
+            name: name.into(),
+            available: names(),
+        })
+}
+
+/// Fills in the placeholders of a template:
+/// - `%DAY_NUMBER%`: the day, e.g. `5`.
+/// - `%DAY_PADDED%`: the two digit day, e.g. `05`.
+/// - `%YEAR%`: the year, e.g. `2025`.
+/// - `%TITLE%`: the puzzle title if the description was downloaded already, `Day 5` otherwise.
+/// - `%PARSER%`: an `Input` type and `parse` function matching the input, or parsing its lines if
+///   it was not downloaded yet. See [`shape`](crate::template::shape).
+/// - `%NAME%` for every `NAME` in `custom`, i.e. the placeholders configured in `aoc.toml`.
+#[must_use]
+pub fn render(
+    template: &str,
+    puzzle: Puzzle,
+    title: Option<&str>,
+    parser: Option<&str>,
+    custom: &BTreeMap<String, String>,
+) -> String {
+    let day = puzzle.day.into_inner();
+    let title = title.map_or_else(|| format!("Day {day}"), str::to_string);
+
+    let mut placeholders = vec![
+        ("DAY_NUMBER".to_string(), day.to_string()),
+        ("DAY_PADDED".to_string(), puzzle.day.to_string()),
+        ("YEAR".to_string(), puzzle.year.to_string()),
+        ("TITLE".to_string(), title),
+        (
+            "PARSER".to_string(),
+            parser.map_or_else(String::new, |parser| format!("\n{parser}")),
+        ),
+    ];
+    placeholders.extend(custom.clone());
+
+    placeholders
+        .iter()
+        .fold(template.to_string(), |template, (name, value)| {
+            template.replace(&format!("%{name}%"), value)
+        })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{BUILTIN_TEMPLATES, DEFAULT_TEMPLATE, read, render};
+    use crate::day;
+    use crate::template::Puzzle;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn fills_placeholders() {
+        let puzzle = Puzzle::new(2025, day!(5));
+        let custom = BTreeMap::from([("AUTHOR".to_string(), "me".to_string())]);
+        let template = "%DAY_NUMBER% %DAY_PADDED% %YEAR% %TITLE% %AUTHOR% %UNKNOWN%";
+
+        assert_eq!(
+            render(template, puzzle, Some("Cafeteria"), None, &custom),
+            "5 05 2025 Cafeteria me %UNKNOWN%"
+        );
+        assert_eq!(
+            render("%TITLE%", puzzle, None, None, &BTreeMap::new()),
+            "Day 5"
+        );
+    }
+
+    #[test]
+    fn fills_all_placeholders_of_builtin_templates() {
+        let puzzle = Puzzle::new(2025, day!(5));
+
+        for (name, _) in BUILTIN_TEMPLATES {
+            let template = read(name).unwrap();
+            let source = render(&template, puzzle, Some("Cafeteria"), None, &BTreeMap::new());
+            assert!(!source.contains('%'), "{name} has unknown placeholders");
+            assert!(source.contains("advent_of_code::solution!(5);"));
+            assert!(source.contains("assert_eq!(result, None);"));
+        }
+    }
+
+    #[test]
+    fn inserts_parser() {
+        let template = read(DEFAULT_TEMPLATE).unwrap();
+        let puzzle = Puzzle::new(2025, day!(5));
+
+        let source = render(&template, puzzle, None, None, &BTreeMap::new());
+        assert!(source.starts_with("advent_of_code::solution!(5);\n\npub fn part_one"));
+
+        let parser = "type Input = Vec<u64>;\n\nfn parse(input: &str) -> Input {\n    todo!()\n}\n";
+        let source = render(&template, puzzle, None, Some(parser), &BTreeMap::new());
+        assert!(source.starts_with(&format!(
+            "advent_of_code::solution!(5);\n\n{parser}\npub fn part_one"
+        )));
+    }
+
+    #[test]
+    fn rejects_unknown_templates() {
+        let error = read("unknown").unwrap_err().to_string();
+        assert!(error.contains("default, grid, graph, parse-once"));
+    }
+}
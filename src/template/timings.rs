@@ -1,9 +1,9 @@
 use std::{collections::HashMap, fs, io::Error, str::FromStr};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{Day, layout};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+const TIMINGS_FILE: &str = "timings.json";
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
@@ -11,6 +11,10 @@ pub struct Timing {
     pub day: Day,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    /// The message of the error part 1 failed with, if any.
+    pub part_1_error: Option<String>,
+    /// The message of the error part 2 failed with, if any.
+    pub part_2_error: Option<String>,
     pub total_nanos: f64,
 }
 
@@ -25,13 +29,13 @@ impl Timings {
     /// Dehydrate timings to a JSON file.
     pub fn store_file(&self) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(layout::store_path(layout::active_year(), TIMINGS_FILE))?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
     pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+        fs::read_to_string(layout::store_path(layout::active_year(), TIMINGS_FILE))
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from)
             .unwrap_or_default()
@@ -51,7 +55,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
@@ -133,6 +137,15 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        for (key, error) in [
+            ("part_1_error", &value.part_1_error),
+            ("part_2_error", &value.part_2_error),
+        ] {
+            if let Some(error) = error {
+                map.insert(key.into(), JsonValue::String(error.clone()));
+            }
+        }
+
         JsonValue::Object(map)
     }
 }
@@ -148,7 +161,7 @@ impl TryFrom<&JsonValue> for Timing {
         let day = json
             .get("day")
             .and_then(|v| v.get::<String>())
-            .and_then(|day| Day::from_str(day).ok())
+            .and_then(|day| Day::parse_any(day))
             .ok_or("Expected timing.day to be a Day struct.")?;
 
         let part_1 = json
@@ -161,6 +174,16 @@ impl TryFrom<&JsonValue> for Timing {
             .map(|v| if v.is_null() { None } else { v.get::<String>() })
             .ok_or("Expected timing.part_2 to be null or string.")?;
 
+        // errors are only stored for parts that failed.
+        let error = |key: &str| -> Result<Option<String>, String> {
+            json.get(key).map_or(Ok(None), |v| {
+                v.get::<String>()
+                    .cloned()
+                    .map(Some)
+                    .ok_or(format!("Expected timing.{key} to be a string."))
+            })
+        };
+
         let total_nanos = json
             .get("total_nanos")
             .and_then(|v| v.get::<f64>().copied())
@@ -170,6 +193,8 @@ impl TryFrom<&JsonValue> for Timing {
             day,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
+            part_1_error: error("part_1_error")?,
+            part_2_error: error("part_2_error")?,
             total_nanos,
         })
     }
@@ -190,18 +215,24 @@ mod tests {
                     day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    part_1_error: None,
+                    part_2_error: None,
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    part_1_error: None,
+                    part_2_error: None,
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: None,
+                    part_1_error: None,
+                    part_2_error: None,
                     total_nanos: 4e+10,
                 },
             ],
@@ -223,6 +254,15 @@ mod tests {
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_part_errors() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "part_2_error": "bad input", "total_nanos": 1000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.part_1_error, None);
+            assert_eq!(timing.part_2_error, Some("bad input".to_string()));
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -281,11 +321,13 @@ mod tests {
                     day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
+                    part_1_error: None,
+                    part_2_error: None,
                     total_nanos: 3_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -295,11 +337,13 @@ mod tests {
                     day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: None,
+                    part_1_error: None,
+                    part_2_error: None,
                     total_nanos: 1_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -309,11 +353,13 @@ mod tests {
                     day: day!(1),
                     part_1: None,
                     part_2: None,
+                    part_1_error: None,
+                    part_2_error: None,
                     total_nanos: 0.0,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
@@ -333,6 +379,8 @@ mod tests {
                     day: day!(3),
                     part_1: None,
                     part_2: None,
+                    part_1_error: None,
+                    part_2_error: None,
                     total_nanos: 0_f64,
                 }],
             };
@@ -353,6 +401,8 @@ mod tests {
                     day: day!(2),
                     part_1: None,
                     part_2: None,
+                    part_1_error: None,
+                    part_2_error: None,
                     total_nanos: 0_f64,
                 }],
             };